@@ -0,0 +1,54 @@
+/// Generates the registry of the solutions in `src/bin`, which is compiled into the main binary
+/// with the `solutions` feature.
+/// This allows `cargo all` and `cargo time` to run solutions without spawning `cargo run` for each day.
+use std::{env, fs, path::Path};
+
+fn main() {
+    // NOTE: without the feature, editing a solution must not rebuild the main binary.
+    if env::var_os("CARGO_FEATURE_SOLUTIONS").is_none() {
+        println!("cargo::rerun-if-changed=build.rs");
+        return;
+    }
+
+    println!("cargo::rerun-if-changed=src/bin");
+
+    let bin_dir = Path::new(&env::var("CARGO_MANIFEST_DIR").unwrap()).join("src/bin");
+
+    // solution binaries are named `<year>_<day>.rs`, e.g. `2025_01.rs`.
+    let mut names: Vec<String> = fs::read_dir(&bin_dir)
+        .map(|entries| {
+            entries
+                .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+                .filter_map(|name| name.strip_suffix(".rs").map(String::from))
+                .filter(|name| {
+                    name.split_once('_').is_some_and(|(year, day)| {
+                        year.len() == 4
+                            && day.len() == 2
+                            && name.chars().all(|c| c.is_ascii_digit() || c == '_')
+                    })
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    names.sort_unstable();
+
+    let mut registry = String::new();
+
+    for name in &names {
+        let path = bin_dir.join(format!("{name}.rs"));
+        registry.push_str(&format!(
+            "#[path = {:?}]\n#[allow(dead_code, unused_imports)]\nmod solution_{name};\n",
+            path.display().to_string()
+        ));
+    }
+
+    registry
+        .push_str("\npub const SOLUTIONS: &[advent_of_code::template::runner::Solution] = &[\n");
+    for name in &names {
+        registry.push_str(&format!("    solution_{name}::SOLUTION,\n"));
+    }
+    registry.push_str("];\n");
+
+    let out_path = Path::new(&env::var("OUT_DIR").unwrap()).join("solutions.rs");
+    fs::write(out_path, registry).unwrap();
+}
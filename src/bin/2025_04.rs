@@ -1,8 +1,9 @@
-advent_of_code::solution!(4);
+advent_of_code::solution!(2025, 4, parse);
 
+use advent_of_code::template::{CharGrid, Color, Visualizer};
 use std::collections::HashSet;
 
-fn parse_input(input: &str) -> HashSet<(usize, usize)> {
+pub fn parse(input: &str) -> HashSet<(usize, usize)> {
     // The grid can be represented as a HashSet storing the coordinates.
     let mut rolls = HashSet::new();
     for (i, line) in input.trim().lines().enumerate() {
@@ -15,11 +16,9 @@ fn parse_input(input: &str) -> HashSet<(usize, usize)> {
     rolls
 }
 
-pub fn part_one(input: &str) -> Option<u64> {
-    let rolls = parse_input(input);
-
+pub fn part_one(rolls: &HashSet<(usize, usize)>) -> Option<u64> {
     let mut total: u64 = 0;
-    for (x, y) in &rolls {
+    for (x, y) in rolls {
         let c = (-1..=1)
             .flat_map(|i| (-1..=1).map(move |j| (i, j)))
             .filter_map(|(i, j)| {
@@ -37,11 +36,30 @@ pub fn part_one(input: &str) -> Option<u64> {
     Some(total)
 }
 
-pub fn part_two(input: &str) -> Option<u64> {
-    let mut rolls = parse_input(input);
+/// Draws the remaining rolls, and the ones removed in the last round in red.
+fn rolls_frame(rolls: &HashSet<(usize, usize)>, removed: &[(usize, usize)]) -> CharGrid {
+    let (height, width) = rolls
+        .iter()
+        .chain(removed)
+        .fold((0, 0), |(h, w), (x, y)| (h.max(x + 1), w.max(y + 1)));
+
+    let mut grid = CharGrid::new(width, height);
+    for (x, y) in rolls {
+        grid.set(*y, *x, '@', Color::Default);
+    }
+    for (x, y) in removed {
+        grid.set(*y, *x, 'x', Color::Red);
+    }
+    grid
+}
 
+// NOTE: Takes ownership of the rolls to remove them, the runner clones them for each run.
+pub fn part_two(mut rolls: HashSet<(usize, usize)>) -> Option<u64> {
     let mut total: u64 = 0;
     let mut removed: bool = true;
+    // Rolls removed in the current round, only tracked with `--visualize`.
+    let mut removed_rolls: Vec<(usize, usize)> = Vec::new();
+    Visualizer::push(|| rolls_frame(&rolls, &[]));
     while removed {
         removed = false;
         for (x, y) in rolls.iter().copied().collect::<Vec<_>>() {
@@ -58,8 +76,13 @@ pub fn part_two(input: &str) -> Option<u64> {
                 removed = true;
                 rolls.remove(&(x, y));
                 total += 1;
+                if Visualizer::is_enabled() {
+                    removed_rolls.push((x, y));
+                }
             }
         }
+        Visualizer::push(|| rolls_frame(&rolls, &removed_rolls));
+        removed_rolls.clear();
     }
 
     Some(total)
@@ -71,13 +94,15 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let input = advent_of_code::template::read_file("examples", PUZZLE);
+        let result = part_one(&parse(&input));
         assert_eq!(result, Some(13_u64));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let input = advent_of_code::template::read_file("examples", PUZZLE);
+        let result = part_two(parse(&input));
         assert_eq!(result, Some(43_u64));
     }
 }
@@ -1,4 +1,4 @@
-advent_of_code::solution!(5);
+advent_of_code::solution!(2025, 5);
 
 fn parse_input(input: &str) -> (Vec<(u64, u64)>, Vec<u64>) {
     let mut split = input.split("\n\n");
@@ -41,7 +41,7 @@ pub fn part_two(input: &str) -> Option<u64> {
     let (mut ranges, _) = parse_input(input);
 
     // NOTE: Sorting is essential for the next part of the algorythm!
-    ranges.sort_by(|(start_1, _), (start_2, _)| start_1.cmp(start_2));
+    ranges.sort_by_key(|(start, _)| *start);
 
     // Iterate through the ranges to check for overlaps.
     let mut counter: u64 = 0;
@@ -71,13 +71,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(3));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(14));
     }
 }
@@ -1,4 +1,6 @@
-advent_of_code::solution!(6);
+advent_of_code::solution!(2025, 6);
+
+use advent_of_code::{aoc_debug, aoc_trace};
 
 fn parse_input(input: &str) -> (Vec<Vec<u64>>, Vec<char>) {
     // The data is arranged in columns.
@@ -57,10 +59,8 @@ pub fn part_two(input: &str) -> Option<u64> {
         })
         .collect();
 
-    if cfg!(test) {
-        for c in &columns {
-            println!("{}", c);
-        }
+    for c in &columns {
+        aoc_debug!("{}", c);
     }
 
     let ops: Vec<_> = lines[lines.len() - 1]
@@ -83,9 +83,7 @@ pub fn part_two(input: &str) -> Option<u64> {
             ops_idx += 1;
         } else {
             // Add numbers to the queue.
-            if cfg!(test) {
-                println!("Parsing: {}", col);
-            }
+            aoc_trace!("Parsing: {}", col);
             queue.push(col.trim().parse::<u64>().unwrap());
         }
     }
@@ -105,13 +103,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(4277556));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(3263827));
     }
 }
@@ -1,27 +1,41 @@
-advent_of_code::solution!(7);
+advent_of_code::solution!(2025, 7);
+
+use advent_of_code::template::{CharGrid, Color, Visualizer};
 
 pub fn part_one(input: &str) -> Option<u64> {
     let width = input.lines().next().unwrap().chars().count();
     let mut beam = vec![0_u64; width];
     let mut total: u64 = 0;
-    for line in input.trim().lines() {
+    // With `--visualize`, the beams are drawn into the manifold one row at a time.
+    let mut frame = Visualizer::is_enabled().then(|| CharGrid::from_text(input.trim()));
+    for (i, line) in input.trim().lines().enumerate() {
         let mut next_beam = beam.clone();
         for (j, c) in line.chars().enumerate() {
             match c {
                 'S' => next_beam[j] = 1,
-                '^' => {
-                    if beam[j] > 0 {
-                        next_beam[j - 1] = 1;
-                        next_beam[j + 1] = 1;
-                        next_beam[j] = 0;
+                '^' if beam[j] > 0 => {
+                    next_beam[j - 1] = 1;
+                    next_beam[j + 1] = 1;
+                    next_beam[j] = 0;
 
-                        total += 1;
+                    total += 1;
+                    if let Some(frame) = &mut frame {
+                        frame.set(j, i, '^', Color::Red);
                     }
                 }
                 _ => (),
             }
         }
         beam = next_beam;
+
+        if let Some(frame) = &mut frame {
+            for (j, _) in beam.iter().enumerate().filter(|(_, b)| **b > 0) {
+                if frame.get(j, i) == Some(('.', Color::Default)) {
+                    frame.set(j, i, '|', Color::Yellow);
+                }
+            }
+            Visualizer::push(|| frame.clone());
+        }
     }
     Some(total)
 }
@@ -35,12 +49,10 @@ pub fn part_two(input: &str) -> Option<u64> {
         for (j, c) in line.chars().enumerate() {
             match c {
                 'S' => next_beam[j] = 1,
-                '^' => {
-                    if beam[j] > 0 {
-                        next_beam[j - 1] += beam[j];
-                        next_beam[j + 1] += beam[j];
-                        next_beam[j] = 0;
-                    }
+                '^' if beam[j] > 0 => {
+                    next_beam[j - 1] += beam[j];
+                    next_beam[j + 1] += beam[j];
+                    next_beam[j] = 0;
                 }
                 _ => (),
             }
@@ -56,13 +68,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(21));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(40));
     }
 }
@@ -1,9 +1,13 @@
-advent_of_code::solution!(8);
+advent_of_code::solution!(2025, 8, parse);
 
+use advent_of_code::aoc_debug;
 use std::collections::HashMap;
 
 type Point = (u64, u64, u64);
 
+/// Two junction boxes and their squared distance.
+type Pair = (Point, Point, u64);
+
 fn square_dist(p1: &Point, p2: &Point) -> u64 {
     let (x1, y1, z1) = p1;
     let (x2, y2, z2) = p2;
@@ -15,24 +19,27 @@ fn square_dist(p1: &Point, p2: &Point) -> u64 {
     dx * dx + dy * dy + dz * dz
 }
 
-fn parse_input(input: &str) -> Vec<Point> {
+fn parse_input(input: &str) -> Result<Vec<Point>, String> {
     let mut output: Vec<_> = Vec::new();
-    for line in input.trim().lines() {
-        if let [x, y, z] = line.split(",").collect::<Vec<&str>>()[..] {
-            output.push((
-                x.parse::<u64>().unwrap(),
-                y.parse::<u64>().unwrap(),
-                z.parse::<u64>().unwrap(),
+    for (i, line) in input.trim().lines().enumerate() {
+        let [x, y, z] = line.split(",").collect::<Vec<&str>>()[..] else {
+            return Err(format!(
+                "line {}: expected 3 comma-separated coordinates",
+                i + 1
             ));
-        }
+        };
+        let parse = |c: &str| {
+            c.parse::<u64>()
+                .map_err(|e| format!("line {}: invalid coordinate `{c}`: {e}", i + 1))
+        };
+        output.push((parse(x)?, parse(y)?, parse(z)?));
     }
-    output
+    Ok(output)
 }
 
-#[allow(clippy::needless_range_loop)]
-pub fn part_one(input: &str) -> Option<u64> {
-    const CONNECTIONS: usize = if cfg!(test) { 10 } else { 1000 };
-    let points = parse_input(input);
+/// Every pair of junction boxes, closest first. Both parts connect the boxes in this order.
+pub fn parse(input: &str) -> Result<Vec<Pair>, String> {
+    let points = parse_input(input)?;
 
     // Compute all distances:
     let mut distances: Vec<_> = Vec::new();
@@ -44,8 +51,14 @@ pub fn part_one(input: &str) -> Option<u64> {
     }
 
     // Sort by distance:
-    distances.sort_by(|(_, _, d1), (_, _, d2)| d1.cmp(d2));
+    distances.sort_by_key(|(_, _, d)| *d);
+
+    Ok(distances)
+}
 
+#[allow(clippy::needless_range_loop)]
+pub fn part_one(distances: &[Pair]) -> Option<u64> {
+    const CONNECTIONS: usize = if cfg!(test) { 10 } else { 1000 };
     // Create coneections:
     // - If any point in the pair is already in a group, add the other point to the group.
     // - If none of the points in the pair ar in a group, then add them to a new group.
@@ -77,11 +90,9 @@ pub fn part_one(input: &str) -> Option<u64> {
         }
     }
 
-    if cfg!(test) {
-        circuits
-            .iter()
-            .for_each(|(p, id)| println!("{:?} : {}", p, id));
-    }
+    circuits
+        .iter()
+        .for_each(|(p, id)| aoc_debug!("{:?} : {}", p, id));
 
     // Count ids:
     let mut ids: HashMap<u64, u64> = HashMap::new();
@@ -95,25 +106,11 @@ pub fn part_one(input: &str) -> Option<u64> {
     Some(result)
 }
 
-pub fn part_two(input: &str) -> Option<u64> {
+pub fn part_two(distances: &[Pair]) -> Option<u64> {
     // Similar to part 1, but keep connecting boxes untill all boxes are in a single circuit.
     // Output the product of the x coordinates of the last boxes to be connected.
     // NOTE: the stop condition for the iteration in this case is that the ammount of points added
     // to the `circuits` HashMap matches the length of the input vector.
-    let points = parse_input(input);
-
-    // Compute all distances:
-    let mut distances: Vec<_> = Vec::new();
-    for (i, p1) in points.iter().enumerate() {
-        for p2 in points[i + 1..].iter() {
-            let dist = square_dist(p1, p2);
-            distances.push((*p1, *p2, dist));
-        }
-    }
-
-    // Sort by distance:
-    distances.sort_by(|(_, _, d1), (_, _, d2)| d1.cmp(d2));
-
     // Create coneections:
     // - If any point in the pair is already in a group, add the other point to the group.
     // - If none of the points in the pair ar in a group, then add them to a new group.
@@ -121,7 +118,7 @@ pub fn part_two(input: &str) -> Option<u64> {
     let mut id_counter: u64 = 0;
     let mut result: u64 = 0;
     let mut removed_ids: u64 = 0;
-    for (p1, p2, _) in &distances {
+    for (p1, p2, _) in distances {
         let p1_id = circuits.get(p1).cloned();
         let p2_id = circuits.get(p2).cloned();
 
@@ -166,13 +163,15 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let input = advent_of_code::template::read_file("examples", PUZZLE);
+        let result = part_one(&parse(&input).unwrap());
         assert_eq!(result, Some(40));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let input = advent_of_code::template::read_file("examples", PUZZLE);
+        let result = part_two(&parse(&input).unwrap());
         assert_eq!(result, Some(25272));
     }
 }
@@ -1,11 +1,11 @@
+use advent_of_code::template::{PointSet, Visualizer};
 use std::collections::{HashMap, HashSet};
 
-advent_of_code::solution!(9);
+advent_of_code::solution!(2025, 9, parse);
 
 type Point = (u64, u64);
 
-#[inline]
-fn parse_input(input: &str) -> Vec<Point> {
+pub fn parse(input: &str) -> Vec<Point> {
     let mut output: Vec<_> = Vec::new();
     for line in input.trim().lines() {
         if let [x, y] = line.split(",").collect::<Vec<&str>>()[..] {
@@ -156,8 +156,7 @@ impl Iterator for RectangleIntoIter {
     }
 }
 
-pub fn part_one(input: &str) -> Option<u64> {
-    let points = parse_input(input);
+pub fn part_one(points: &[Point]) -> Option<u64> {
     let mut largest: u64 = 0;
     for (i, p1) in points.iter().enumerate() {
         for p2 in points[i + 1..].iter() {
@@ -167,9 +166,14 @@ pub fn part_one(input: &str) -> Option<u64> {
     Some(largest)
 }
 
-pub fn part_two(input: &str) -> Option<u64> {
+pub fn part_two(mut points: Vec<Point>) -> Option<u64> {
     // Create a compressed coordinate system, enconding empty rows and columns as their width.
-    let mut points = parse_input(input);
+    Visualizer::push(|| {
+        points
+            .iter()
+            .map(|&(x, y)| (x as i64, y as i64))
+            .collect::<PointSet>()
+    });
 
     // Append the first point to the back of the array to create the closing segment.
     points.push(points[0]);
@@ -246,9 +250,12 @@ pub fn part_two(input: &str) -> Option<u64> {
             // Since the boundary is one single closed line, we only need to look at the perimeter
             // of the rectangle.
             let a = rect.start;
-            let b = (rect.start.0 + rect.height, rect.start.1);
-            let c = (rect.start.0 + rect.height, rect.start.1 + rect.width);
-            let d = (rect.start.0, rect.start.1 + rect.width);
+            let b = (rect.start.0 + rect.height - 1, rect.start.1);
+            let c = (
+                rect.start.0 + rect.height - 1,
+                rect.start.1 + rect.width - 1,
+            );
+            let d = (rect.start.0, rect.start.1 + rect.width - 1);
             for p in Rectangle::from_points(a, b) {
                 if outside.contains(&p) {
                     continue 'second;
@@ -282,13 +289,17 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&parse(&advent_of_code::template::read_file(
+            "examples", PUZZLE,
+        )));
         assert_eq!(result, Some(50));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(parse(&advent_of_code::template::read_file(
+            "examples", PUZZLE,
+        )));
         assert_eq!(result, Some(24));
     }
 }
@@ -1,9 +1,9 @@
-advent_of_code::solution!(10);
+advent_of_code::solution!(2025, 10, parse);
 
 use std::collections::HashMap;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
-struct Machine {
+pub struct Machine {
     lights: u16,
     buttons: Vec<u16>,
     joltage: Vec<u16>,
@@ -274,30 +274,25 @@ impl std::fmt::Display for Machine {
     }
 }
 
-fn parse_input(input: &str) -> Vec<Machine> {
+pub fn parse(input: &str) -> Vec<Machine> {
     let n = input.trim().lines().count();
 
     // NOTE: pre-allocate with capacity for performance.
     let mut machines: Vec<Machine> = Vec::with_capacity(n);
-    for line in input.lines() {
+    for line in input.trim().lines() {
         machines.push(Machine::from_str(line));
     }
 
     machines
 }
 
-pub fn part_one(input: &str) -> Option<u64> {
-    let machines = parse_input(input);
-
+pub fn part_one(machines: &[Machine]) -> Option<u64> {
     let total = machines.iter().map(|m| m.configure_lights()).sum::<u64>();
     Some(total)
 }
 
-pub fn part_two(input: &str) -> Option<u64> {
-    let mut total: u64 = 0;
-    for line in input.trim().lines() {
-        total += Machine::from_str(line).configure_joltage();
-    }
+pub fn part_two(machines: &[Machine]) -> Option<u64> {
+    let total = machines.iter().map(|m| m.configure_joltage()).sum::<u64>();
     Some(total)
 }
 
@@ -307,13 +302,17 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&parse(&advent_of_code::template::read_file(
+            "examples", PUZZLE,
+        )));
         assert_eq!(result, Some(7));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&parse(&advent_of_code::template::read_file(
+            "examples", PUZZLE,
+        )));
         assert_eq!(result, Some(33));
     }
 }
@@ -1,81 +1,251 @@
-use advent_of_code::template::commands::{all, download, read, scaffold, solve, time};
+use advent_of_code::template::commands::{all, download, read, scaffold, solve, test_day, time};
 use args::{AppArguments, parse};
 
 #[cfg(feature = "today")]
-use advent_of_code::template::Day;
-#[cfg(feature = "today")]
-use std::process;
+use advent_of_code::template::commands::today;
 
 mod args {
-    use advent_of_code::template::Day;
-    use std::process;
+    use advent_of_code::template::{
+        BenchConfig, BenchFigure, Day, DaySet, Part, PuzzleId, RunLimits, VisualizeMode, Year,
+        take_visualize, trace::verbosity_from_args,
+    };
+    use std::{env, error::Error, ffi::OsString, process, str::FromStr};
 
     pub enum AppArguments {
         Download {
-            day: Day,
+            puzzles: Vec<PuzzleId>,
         },
         Read {
-            day: Day,
+            puzzle: PuzzleId,
         },
         Scaffold {
-            day: Day,
+            puzzles: Vec<PuzzleId>,
             download: bool,
             overwrite: bool,
         },
         Solve {
-            day: Day,
+            puzzles: Vec<PuzzleId>,
             release: bool,
             dhat: bool,
-            submit: Option<u8>,
+            submit: Option<Part>,
+            accept: Option<Part>,
+            bench: Option<BenchConfig>,
+            verbosity: u8,
+            visualize: Option<VisualizeMode>,
+            watch: bool,
+        },
+        TestDay {
+            puzzle: PuzzleId,
+            watch: bool,
         },
         All {
+            puzzles: Vec<PuzzleId>,
             release: bool,
+            check: bool,
+            limits: RunLimits,
+            verbosity: u8,
+            jobs: usize,
         },
         Time {
             all: bool,
-            day: Option<Day>,
+            year: Year,
+            puzzles: Option<Vec<PuzzleId>>,
             store: bool,
+            figure: BenchFigure,
+            bench: BenchConfig,
+            limits: RunLimits,
+            memory: bool,
         },
         #[cfg(feature = "today")]
-        Today,
+        Today {
+            wait: bool,
+        },
+    }
+
+    /// Parses the free `[year] [day]` or `[year] [days]` arguments of a command.
+    /// A leading value is treated as a year if it's a valid one, otherwise as the day part.
+    fn opt_year_and<T>(
+        args: &mut pico_args::Arguments,
+    ) -> Result<(Option<Year>, Option<T>), Box<dyn Error>>
+    where
+        T: FromStr,
+        T::Err: Error + 'static,
+    {
+        let Some(first) = args.opt_free_from_str::<String>()? else {
+            return Ok((None, None));
+        };
+
+        match first.parse::<Year>() {
+            Ok(year) => Ok((Some(year), args.opt_free_from_str()?)),
+            Err(_) => Ok((None, Some(first.parse()?))),
+        }
+    }
+
+    /// Falls back to the `AOC_YEAR` configured in `.cargo/config.toml` if no year was passed.
+    fn year_or_default(year: Option<Year>) -> Result<Year, Box<dyn Error>> {
+        year.or_else(Year::from_env)
+            .ok_or_else(|| "no year passed and `AOC_YEAR` is not set to a valid year.".into())
+    }
+
+    /// Resolves a set of days to the puzzles of the year's event calendar.
+    fn puzzles_in(year: Year, days: &DaySet) -> Result<Vec<PuzzleId>, Box<dyn Error>> {
+        let puzzles: Vec<PuzzleId> = days.puzzles(year).collect();
+        if puzzles.is_empty() {
+            return Err(format!(
+                "none of the days `{days}` are part of the {year} event, which has {} days.",
+                year.calendar().day_count()
+            )
+            .into());
+        }
+        Ok(puzzles)
+    }
+
+    fn puzzle(args: &mut pico_args::Arguments) -> Result<PuzzleId, Box<dyn Error>> {
+        match opt_year_and::<Day>(args)? {
+            (year, Some(day)) => {
+                let year = year_or_default(year)?;
+                PuzzleId::new(year, day).ok_or_else(|| {
+                    format!(
+                        "the {year} event only has {} days.",
+                        year.calendar().day_count()
+                    )
+                    .into()
+                })
+            }
+            (year, None) => Err(format!(
+                "expecting a day number between 1 and {}.",
+                year_or_default(year)?.calendar().day_count()
+            )
+            .into()),
+        }
+    }
+
+    fn puzzles(args: &mut pico_args::Arguments) -> Result<Vec<PuzzleId>, Box<dyn Error>> {
+        match opt_year_and::<DaySet>(args)? {
+            (year, Some(days)) => puzzles_in(year_or_default(year)?, &days),
+            (year, None) => Err(format!(
+                "expecting a day number between 1 and {} or a set of days.",
+                year_or_default(year)?.calendar().day_count()
+            )
+            .into()),
+        }
     }
 
-    pub fn parse() -> Result<AppArguments, Box<dyn std::error::Error>> {
-        let mut args = pico_args::Arguments::from_env();
+    pub fn parse() -> Result<AppArguments, Box<dyn Error>> {
+        let mut raw_args: Vec<OsString> = env::args_os().skip(1).collect();
+        let visualize = take_visualize(&mut raw_args);
+        let mut args = pico_args::Arguments::from_vec(raw_args);
 
         let app_args = match args.subcommand()?.as_deref() {
-            Some("all") => AppArguments::All {
-                release: args.contains("--release"),
-            },
+            Some("all") => {
+                let release = args.contains("--release");
+                let check = args.contains("--check");
+                let limits = RunLimits::from_args(&mut args)?;
+                let verbosity = verbosity_from_args(&mut args);
+                let jobs: usize = args.opt_value_from_str(["-j", "--jobs"])?.unwrap_or(1);
+                let (year, days) = opt_year_and::<DaySet>(&mut args)?;
+
+                if jobs == 0 {
+                    return Err("`--jobs` expects at least one job.".into());
+                }
+
+                AppArguments::All {
+                    puzzles: puzzles_in(year_or_default(year)?, &days.unwrap_or_else(DaySet::all))?,
+                    release,
+                    check,
+                    limits,
+                    verbosity,
+                    jobs,
+                }
+            }
             Some("time") => {
                 let all = args.contains("--all");
                 let store = args.contains("--store");
+                let memory = args.contains("--memory");
+                let figure = args.opt_value_from_str("--figure")?.unwrap_or_default();
+                let bench = BenchConfig::from_args(&mut args)?;
+                let limits = RunLimits::from_args(&mut args)?;
+                let (year, days) = opt_year_and::<DaySet>(&mut args)?;
+                let year = year_or_default(year)?;
 
                 AppArguments::Time {
                     all,
-                    day: args.opt_free_from_str()?,
+                    year,
+                    puzzles: days.map(|days| puzzles_in(year, &days)).transpose()?,
                     store,
+                    figure,
+                    bench,
+                    limits,
+                    memory,
                 }
             }
             Some("download") => AppArguments::Download {
-                day: args.free_from_str()?,
+                puzzles: puzzles(&mut args)?,
             },
             Some("read") => AppArguments::Read {
-                day: args.free_from_str()?,
-            },
-            Some("scaffold") => AppArguments::Scaffold {
-                day: args.free_from_str()?,
-                download: args.contains("--download"),
-                overwrite: args.contains("--overwrite"),
-            },
-            Some("solve") => AppArguments::Solve {
-                day: args.free_from_str()?,
-                release: args.contains("--release"),
-                submit: args.opt_value_from_str("--submit")?,
-                dhat: args.contains("--dhat"),
+                puzzle: puzzle(&mut args)?,
             },
+            Some("scaffold") => {
+                let download = args.contains("--download");
+                let overwrite = args.contains("--overwrite");
+
+                AppArguments::Scaffold {
+                    puzzles: puzzles(&mut args)?,
+                    download,
+                    overwrite,
+                }
+            }
+            Some("solve") => {
+                let release = args.contains("--release");
+                let submit = args.opt_value_from_str("--submit")?;
+                let accept = args.opt_value_from_str("--accept")?;
+                let dhat = args.contains("--dhat");
+                let time = args.contains("--time");
+                let bench = BenchConfig::from_args(&mut args)?;
+                let verbosity = verbosity_from_args(&mut args);
+                let watch = args.contains("--watch");
+                let puzzles = puzzles(&mut args)?;
+
+                if watch && puzzles.len() > 1 {
+                    return Err("`--watch` can only be used with a single day.".into());
+                }
+
+                if watch && (submit.is_some() || accept.is_some()) {
+                    return Err("`--watch` can't be combined with `--submit` or `--accept`.".into());
+                }
+
+                if submit.is_some() && puzzles.len() > 1 {
+                    return Err("`--submit` can only be used with a single day.".into());
+                }
+
+                if accept.is_some() && puzzles.len() > 1 {
+                    return Err("`--accept` can only be used with a single day.".into());
+                }
+
+                AppArguments::Solve {
+                    puzzles,
+                    release,
+                    submit,
+                    accept,
+                    dhat,
+                    bench: time.then_some(bench),
+                    verbosity,
+                    visualize,
+                    watch,
+                }
+            }
+            Some("test-day") => {
+                let watch = args.contains("--watch");
+
+                AppArguments::TestDay {
+                    puzzle: puzzle(&mut args)?,
+                    watch,
+                }
+            }
             #[cfg(feature = "today")]
-            Some("today") => AppArguments::Today,
+            Some("today") => AppArguments::Today {
+                wait: args.contains("--wait"),
+            },
             Some(x) => {
                 eprintln!("Unknown command: {x}");
                 process::exit(1);
@@ -86,6 +256,10 @@ mod args {
             }
         };
 
+        if visualize.is_some() && !matches!(app_args, AppArguments::Solve { .. }) {
+            return Err("`--visualize` can only be used with `solve`.".into());
+        }
+
         let remaining = args.finish();
         if !remaining.is_empty() {
             eprintln!("Warning: unknown argument(s): {remaining:?}.");
@@ -95,6 +269,20 @@ mod args {
     }
 }
 
+/// The solutions of `src/bin`, registered by `build.rs` with the `solutions` feature.
+#[cfg(all(feature = "solutions", not(any(test, feature = "dhat-heap"))))]
+mod solutions {
+    include!(concat!(env!("OUT_DIR"), "/solutions.rs"));
+}
+
+/// NOTE: without the `solutions` feature, a broken solution can't break the other commands, and
+/// solutions are run with `cargo run` instead.
+/// With `dhat-heap`, every solution defines its own global allocator, so they run in their own process as well.
+#[cfg(not(all(feature = "solutions", not(any(test, feature = "dhat-heap")))))]
+mod solutions {
+    pub const SOLUTIONS: &[advent_of_code::template::runner::Solution] = &[];
+}
+
 fn main() {
     match parse() {
         Err(err) => {
@@ -102,43 +290,80 @@ fn main() {
             std::process::exit(1);
         }
         Ok(args) => match args {
-            AppArguments::All { release } => all::handle(release),
-            AppArguments::Time { day, all, store } => time::handle(day, all, store),
-            AppArguments::Download { day } => download::handle(day),
-            AppArguments::Read { day } => read::handle(day),
+            AppArguments::All {
+                puzzles,
+                release,
+                check,
+                limits,
+                verbosity,
+                jobs,
+            } => all::handle(
+                &puzzles,
+                release,
+                check,
+                limits,
+                verbosity,
+                jobs,
+                solutions::SOLUTIONS,
+            ),
+            AppArguments::Time {
+                year,
+                puzzles,
+                all,
+                store,
+                figure,
+                bench,
+                limits,
+                memory,
+            } => time::handle(
+                year,
+                puzzles,
+                all,
+                store,
+                figure,
+                bench,
+                limits,
+                memory,
+                solutions::SOLUTIONS,
+            ),
+            AppArguments::Download { puzzles } => {
+                for puzzle in puzzles {
+                    download::handle(puzzle);
+                }
+            }
+            AppArguments::Read { puzzle } => read::handle(puzzle),
             AppArguments::Scaffold {
-                day,
+                puzzles,
                 download,
                 overwrite,
             } => {
-                scaffold::handle(day, overwrite);
-                if download {
-                    download::handle(day);
+                for puzzle in puzzles {
+                    scaffold::handle(puzzle, overwrite);
+                    if download {
+                        download::handle(puzzle);
+                    }
                 }
             }
             AppArguments::Solve {
-                day,
+                puzzles,
                 release,
                 dhat,
                 submit,
-            } => solve::handle(day, release, dhat, submit),
-            #[cfg(feature = "today")]
-            AppArguments::Today => {
-                match Day::today() {
-                    Some(day) => {
-                        scaffold::handle(day, false);
-                        download::handle(day);
-                        read::handle(day)
-                    }
-                    None => {
-                        eprintln!(
-                            "`today` command can only be run between the 1st and \
-                            the 25th of december. Please use `scaffold` with a specific day."
-                        );
-                        process::exit(1)
-                    }
-                };
+                accept,
+                bench,
+                verbosity,
+                visualize,
+                watch,
+            } => {
+                for puzzle in puzzles {
+                    solve::handle(
+                        puzzle, release, dhat, submit, accept, bench, verbosity, visualize, watch,
+                    );
+                }
             }
+            AppArguments::TestDay { puzzle, watch } => test_day::handle(puzzle, watch),
+            #[cfg(feature = "today")]
+            AppArguments::Today { wait } => today::handle(wait),
         },
     };
 }
@@ -0,0 +1,279 @@
+use std::convert::Infallible;
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// The answer of a puzzle part, in the form it is submitted in.
+///
+/// Parts can return any `Display` value. The runner reads its output back as an [`Answer`],
+/// so that multi-line outputs are treated as letter grids and read with [`Answer::letters`].
+/// Parts can also return an [`Answer`] directly, e.g. to render a set of points.
+///
+/// ```
+/// # use advent_of_code::template::Answer;
+/// let answer = Answer::from_points([(0, 0), (0, 1), (1, 1), (2, 0), (2, 1), (2, 2)]);
+/// assert_eq!(answer.to_string(), "#.#\n###\n..#");
+/// assert_eq!(Answer::from(u128::MAX / 2), Answer::Integer(i128::MAX));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Integer(i128),
+    Text(String),
+    /// Rows of pixels, drawn with `#` (or any other character) for lit and `.` or ` ` for dark pixels.
+    Grid(Vec<String>),
+}
+
+impl Answer {
+    /// Creates a grid of `#` and `.` pixels with the given `(x, y)` coordinates lit.
+    pub fn from_points(points: impl IntoIterator<Item = (usize, usize)>) -> Self {
+        let points: Vec<(usize, usize)> = points.into_iter().collect();
+        let width = points.iter().map(|(x, _)| x + 1).max().unwrap_or(0);
+        let height = points.iter().map(|(_, y)| y + 1).max().unwrap_or(0);
+
+        let mut rows = vec![vec!['.'; width]; height];
+        for (x, y) in points {
+            rows[y][x] = '#';
+        }
+
+        Self::Grid(rows.into_iter().map(String::from_iter).collect())
+    }
+
+    /// Reads the letters of a grid, if it is drawn in the block-letter font of Advent of Code.
+    pub fn letters(&self) -> Option<String> {
+        match self {
+            Self::Grid(rows) => read_letters(rows),
+            _ => None,
+        }
+    }
+
+    /// The string to submit for this answer. `None` if the letters of a grid can't be read.
+    pub fn submission(&self) -> Option<String> {
+        match self {
+            Self::Integer(n) => Some(n.to_string()),
+            Self::Text(s) => Some(s.clone()),
+            Self::Grid(_) => self.letters(),
+        }
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Integer(n) => write!(f, "{n}"),
+            Self::Text(s) => f.write_str(s),
+            Self::Grid(rows) => f.write_str(&rows.join("\n")),
+        }
+    }
+}
+
+/// Reads the output of a part. Multi-line outputs are read as grids.
+/// Numbers are only read as integers if they are written the same way, e.g. "007" stays text.
+impl FromStr for Answer {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.contains('\n') {
+            return Ok(Self::Grid(s.lines().map(String::from).collect()));
+        }
+
+        match s.parse::<i128>() {
+            Ok(n) if n.to_string() == s => Ok(Self::Integer(n)),
+            _ => Ok(Self::Text(s.into())),
+        }
+    }
+}
+
+macro_rules! impl_from_integer {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(n: $t) -> Self {
+                Self::Integer(n.into())
+            }
+        })*
+    };
+}
+
+impl_from_integer!(u8, u16, u32, u64, i8, i16, i32, i64, i128);
+
+impl From<usize> for Answer {
+    fn from(n: usize) -> Self {
+        Self::Integer(n as i128)
+    }
+}
+
+impl From<isize> for Answer {
+    fn from(n: isize) -> Self {
+        Self::Integer(n as i128)
+    }
+}
+
+/// Answers beyond `i128::MAX` are kept as text.
+impl From<u128> for Answer {
+    fn from(n: u128) -> Self {
+        i128::try_from(n).map_or_else(|_| Self::Text(n.to_string()), Self::Integer)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Self::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Self::Text(s.into())
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+const LETTER_HEIGHT: usize = 6;
+
+/// The block-letter font of Advent of Code. Letters are separated by at least one dark column.
+const FONT: [(char, &str); 18] = [
+    ('A', ".##.|#..#|#..#|####|#..#|#..#"),
+    ('B', "###.|#..#|###.|#..#|#..#|###."),
+    ('C', ".##.|#..#|#...|#...|#..#|.##."),
+    ('E', "####|#...|###.|#...|#...|####"),
+    ('F', "####|#...|###.|#...|#...|#..."),
+    ('G', ".##.|#..#|#...|#.##|#..#|.###"),
+    ('H', "#..#|#..#|####|#..#|#..#|#..#"),
+    ('I', "###|.#.|.#.|.#.|.#.|###"),
+    ('J', "..##|...#|...#|...#|#..#|.##."),
+    ('K', "#..#|#.#.|##..|#.#.|#.#.|#..#"),
+    ('L', "#...|#...|#...|#...|#...|####"),
+    ('O', ".##.|#..#|#..#|#..#|#..#|.##."),
+    ('P', "###.|#..#|#..#|###.|#...|#..."),
+    ('R', "###.|#..#|#..#|###.|#.#.|#..#"),
+    ('S', ".###|#...|#...|.##.|...#|###."),
+    ('U', "#..#|#..#|#..#|#..#|#..#|.##."),
+    ('Y', "#...#|#...#|.#.#.|..#..|..#..|..#.."),
+    ('Z', "####|...#|..#.|.#..|#...|####"),
+];
+
+fn is_lit(c: char) -> bool {
+    !matches!(c, '.' | ' ')
+}
+
+/// Encodes each column of pixels as a bitmask, with the top row as the lowest bit.
+fn columns(rows: &[Vec<bool>]) -> Vec<u8> {
+    let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+    (0..width)
+        .map(|x| {
+            rows.iter()
+                .enumerate()
+                .filter(|(_, row)| row.get(x).copied().unwrap_or(false))
+                .fold(0, |mask, (y, _)| mask | 1 << y)
+        })
+        .collect()
+}
+
+fn read_letters(rows: &[String]) -> Option<String> {
+    let pixels: Vec<Vec<bool>> = rows
+        .iter()
+        .map(|row| row.chars().map(is_lit).collect())
+        .collect();
+
+    // ignore dark rows around the letters.
+    let first = pixels.iter().position(|row| row.contains(&true))?;
+    let last = pixels.iter().rposition(|row| row.contains(&true))?;
+    if last - first + 1 != LETTER_HEIGHT {
+        return None;
+    }
+
+    let font: Vec<(char, Vec<u8>)> = FONT
+        .iter()
+        .map(|(letter, glyph)| {
+            let rows: Vec<Vec<bool>> = glyph
+                .split('|')
+                .map(|row| row.chars().map(is_lit).collect())
+                .collect();
+            (*letter, columns(&rows))
+        })
+        .collect();
+
+    columns(&pixels[first..=last])
+        .split(|column| *column == 0)
+        .filter(|glyph| !glyph.is_empty())
+        .map(|glyph| {
+            font.iter()
+                .find(|(_, columns)| columns == glyph)
+                .map(|(letter, _)| *letter)
+        })
+        .collect()
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Answer;
+
+    const HELLO: &str = "\
+#..#.####.#....#.....##.
+#..#.#....#....#....#..#
+####.###..#....#....#..#
+#..#.#....#....#....#..#
+#..#.#....#....#....#..#
+#..#.####.####.####..##.";
+
+    #[test]
+    fn reads_answers() {
+        assert_eq!("42".parse::<Answer>().unwrap(), Answer::Integer(42));
+        assert_eq!(
+            "-170141183460469231731687303715884105728"
+                .parse::<Answer>()
+                .unwrap(),
+            Answer::Integer(i128::MIN)
+        );
+        assert_eq!(
+            "abc-def".parse::<Answer>().unwrap(),
+            Answer::Text("abc-def".into())
+        );
+        assert_eq!(
+            "01234567".parse::<Answer>().unwrap(),
+            Answer::Text("01234567".into())
+        );
+        assert_eq!("+5".parse::<Answer>().unwrap(), Answer::Text("+5".into()));
+        assert_eq!("-0".parse::<Answer>().unwrap(), Answer::Text("-0".into()));
+        assert_eq!(
+            "#.\n.#".parse::<Answer>().unwrap(),
+            Answer::Grid(vec!["#.".into(), ".#".into()])
+        );
+    }
+
+    #[test]
+    fn reads_letters() {
+        let answer: Answer = HELLO.parse().unwrap();
+        assert_eq!(answer.letters().as_deref(), Some("HELLO"));
+        assert_eq!(answer.submission().as_deref(), Some("HELLO"));
+        assert_eq!(answer.to_string(), HELLO);
+    }
+
+    #[test]
+    fn reads_letters_with_other_pixels() {
+        let grid = format!("\n{}\n", HELLO.replace('#', "█").replace('.', " "));
+        let answer: Answer = grid.parse().unwrap();
+        assert_eq!(answer.letters().as_deref(), Some("HELLO"));
+    }
+
+    #[test]
+    fn rejects_unknown_letters() {
+        let answer: Answer = HELLO.replace("#..#.####", "#..#.#.##").parse().unwrap();
+        assert_eq!(answer.letters(), None);
+        assert_eq!(answer.submission(), None);
+
+        let answer: Answer = "#\n#".parse().unwrap();
+        assert_eq!(answer.letters(), None);
+    }
+
+    #[test]
+    fn submits_numbers_and_text() {
+        assert_eq!(Answer::from(7_usize).submission().as_deref(), Some("7"));
+        assert_eq!(
+            Answer::from(u128::MAX).submission(),
+            Some(u128::MAX.to_string())
+        );
+        assert_eq!(Answer::from("abc").submission().as_deref(), Some("abc"));
+    }
+}
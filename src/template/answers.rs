@@ -0,0 +1,295 @@
+use std::{
+    collections::HashMap,
+    fs,
+    io::{Error, ErrorKind},
+    str::FromStr,
+};
+use tinyjson::JsonValue;
+
+use crate::template::{Day, Part, PuzzleId, Year};
+
+static ANSWERS_FILE_PATH: &str = "./data/answers.json";
+
+/// Represents the accepted answers of a single puzzle.
+#[derive(Clone, Debug)]
+pub struct KnownAnswer {
+    pub puzzle: PuzzleId,
+    pub part_1: Option<String>,
+    pub part_2: Option<String>,
+}
+
+/// Represents the accepted answers for a set of puzzles.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default)]
+pub struct Answers {
+    pub data: Vec<KnownAnswer>,
+}
+
+/// The result of checking an answer against the known answers.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AnswerCheck {
+    /// No answer is known for the part.
+    Unknown,
+    Correct,
+    Incorrect {
+        expected: String,
+    },
+}
+
+impl Answers {
+    /// Dehydrate answers to a JSON file.
+    pub fn store_file(&self) -> Result<(), Error> {
+        let json = JsonValue::from(self.clone());
+        let mut file = fs::File::create(ANSWERS_FILE_PATH)?;
+        json.format_to(&mut file)
+    }
+
+    /// Rehydrate answers from a JSON file. If not present, returns empty answers.
+    /// A file that can't be read is an error, so that it is never overwritten with empty answers.
+    pub fn read_from_file() -> Result<Self, String> {
+        Self::read_from_path(ANSWERS_FILE_PATH)
+    }
+
+    fn read_from_path(path: &str) -> Result<Self, String> {
+        match fs::read_to_string(path) {
+            Ok(contents) => Answers::try_from(contents)
+                .map_err(|e| format!("\"{path}\" could not be parsed: {e}")),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(format!("\"{path}\" could not be read: {e}")),
+        }
+    }
+
+    /// Returns the accepted answer of a part, if known.
+    pub fn get(&self, puzzle: PuzzleId, part: Part) -> Option<&str> {
+        let answer = self.data.iter().find(|a| a.puzzle == puzzle)?;
+        match part {
+            Part::One => answer.part_1.as_deref(),
+            Part::Two => answer.part_2.as_deref(),
+        }
+    }
+
+    /// Records the accepted answer of a part, replacing a previously known one.
+    pub fn set(&mut self, puzzle: PuzzleId, part: Part, value: &str) {
+        let index = match self.data.iter().position(|a| a.puzzle == puzzle) {
+            Some(index) => index,
+            None => {
+                self.data.push(KnownAnswer {
+                    puzzle,
+                    part_1: None,
+                    part_2: None,
+                });
+                self.data.sort_unstable_by_key(|a| a.puzzle);
+                self.data.iter().position(|a| a.puzzle == puzzle).unwrap()
+            }
+        };
+
+        let answer = &mut self.data[index];
+        match part {
+            Part::One => answer.part_1 = Some(value.into()),
+            Part::Two => answer.part_2 = Some(value.into()),
+        }
+    }
+
+    /// Checks a result against the accepted answer of a part.
+    pub fn check(&self, puzzle: PuzzleId, part: Part, result: Option<&str>) -> AnswerCheck {
+        match self.get(puzzle, part) {
+            None => AnswerCheck::Unknown,
+            Some(expected) if Some(expected) == result => AnswerCheck::Correct,
+            Some(expected) => AnswerCheck::Incorrect {
+                expected: expected.into(),
+            },
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<Answers> for JsonValue {
+    fn from(value: Answers) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "data".into(),
+            JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for Answers {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json_data = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?
+            .get("data")
+            .ok_or("expected JSON document to have key `data`.")?
+            .get::<Vec<JsonValue>>()
+            .ok_or("expected `json.data` to be an array.")?;
+
+        Ok(Answers {
+            data: json_data
+                .iter()
+                .map(KnownAnswer::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&KnownAnswer> for JsonValue {
+    fn from(value: &KnownAnswer) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "year".into(),
+            JsonValue::String(value.puzzle.year.to_string()),
+        );
+        map.insert(
+            "day".into(),
+            JsonValue::String(value.puzzle.day.to_string()),
+        );
+
+        for (key, part) in [("part_1", &value.part_1), ("part_2", &value.part_2)] {
+            map.insert(
+                key.into(),
+                match part {
+                    Some(x) => JsonValue::String(x.clone()),
+                    None => JsonValue::Null,
+                },
+            );
+        }
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for KnownAnswer {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected answer to be a JSON object.")?;
+
+        let year = json
+            .get("year")
+            .and_then(|v| v.get::<String>())
+            .and_then(|year| Year::from_str(year).ok())
+            .ok_or("Expected answer.year to be a Year struct.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected answer.day to be a Day struct.")?;
+
+        let puzzle = PuzzleId::new(year, day)
+            .ok_or("Expected answer.day to be part of the year's event calendar.")?;
+
+        let part_1 = json
+            .get("part_1")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected answer.part_1 to be null or string.")?;
+
+        let part_2 = json
+            .get("part_2")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected answer.part_2 to be null or string.")?;
+
+        Ok(KnownAnswer {
+            puzzle,
+            part_1: part_1.cloned(),
+            part_2: part_2.cloned(),
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{env, fs, process};
+
+    use super::{AnswerCheck, Answers};
+    use crate::{puzzle, template::Part};
+
+    #[test]
+    fn handles_json_answers() {
+        let json =
+            r#"{ "data": [{ "year": "2025", "day": "01", "part_1": "42", "part_2": null }] }"#
+                .to_string();
+        let answers = Answers::try_from(json).unwrap();
+        assert_eq!(answers.get(puzzle!(2025, 1), Part::One), Some("42"));
+        assert_eq!(answers.get(puzzle!(2025, 1), Part::Two), None);
+        assert_eq!(answers.get(puzzle!(2025, 2), Part::One), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_for_malformed_answers() {
+        let json = r#"{ "data": [{ "year": "2025", "day": "01" }] }"#.to_string();
+        Answers::try_from(json).unwrap();
+    }
+
+    #[test]
+    fn sets_and_round_trips_answers() {
+        let mut answers = Answers::default();
+        answers.set(puzzle!(2025, 3), Part::Two, "7");
+        answers.set(puzzle!(2025, 1), Part::One, "1");
+        answers.set(puzzle!(2025, 1), Part::One, "2");
+
+        let json = tinyjson::JsonValue::from(answers).stringify().unwrap();
+        let answers = Answers::try_from(json).unwrap();
+
+        assert_eq!(answers.data.len(), 2);
+        assert_eq!(answers.data[0].puzzle, puzzle!(2025, 1));
+        assert_eq!(answers.get(puzzle!(2025, 1), Part::One), Some("2"));
+        assert_eq!(answers.get(puzzle!(2025, 3), Part::Two), Some("7"));
+    }
+
+    #[test]
+    fn checks_answers() {
+        let mut answers = Answers::default();
+        answers.set(puzzle!(2025, 1), Part::One, "42");
+
+        assert_eq!(
+            answers.check(puzzle!(2025, 1), Part::One, Some("42")),
+            AnswerCheck::Correct
+        );
+        assert_eq!(
+            answers.check(puzzle!(2025, 1), Part::One, Some("41")),
+            AnswerCheck::Incorrect {
+                expected: "42".into()
+            }
+        );
+        assert_eq!(
+            answers.check(puzzle!(2025, 1), Part::One, None),
+            AnswerCheck::Incorrect {
+                expected: "42".into()
+            }
+        );
+        assert_eq!(
+            answers.check(puzzle!(2025, 1), Part::Two, Some("42")),
+            AnswerCheck::Unknown
+        );
+    }
+
+    #[test]
+    fn reads_only_valid_files() {
+        let path = env::temp_dir().join(format!("aoc-answers-test-{}.json", process::id()));
+        let path = path.to_str().unwrap();
+
+        assert!(Answers::read_from_path(path).unwrap().data.is_empty());
+
+        fs::write(path, "{ \"data\": [{ \"year\": 2025 }] }").unwrap();
+        let result = Answers::read_from_path(path);
+        fs::remove_file(path).unwrap();
+        assert!(result.is_err());
+    }
+}
@@ -1,16 +1,23 @@
 /// Wrapper module around the "aoc-cli" command-line.
 use std::{
     fmt::Display,
+    fs,
+    path::Path,
     process::{Command, Output, Stdio},
+    sync::LazyLock,
 };
 
-use crate::template::Day;
+use regex::Regex;
+
+use crate::template::{Part, PuzzleId};
 
 #[derive(Debug)]
 pub enum AocCommandError {
     CommandNotFound,
     CommandNotCallable,
+    PuzzleLocked,
     BadExitStatus(Output),
+    UnknownResponse(Output),
 }
 
 impl Display for AocCommandError {
@@ -18,9 +25,13 @@ impl Display for AocCommandError {
         match self {
             AocCommandError::CommandNotFound => write!(f, "aoc-cli is not present in environment."),
             AocCommandError::CommandNotCallable => write!(f, "aoc-cli could not be called."),
+            AocCommandError::PuzzleLocked => write!(f, "the puzzle has not been unlocked yet."),
             AocCommandError::BadExitStatus(_) => {
                 write!(f, "aoc-cli exited with a non-zero status.")
             }
+            AocCommandError::UnknownResponse(_) => {
+                write!(f, "the response of aoc-cli could not be read.")
+            }
         }
     }
 }
@@ -33,8 +44,8 @@ pub fn check() -> Result<(), AocCommandError> {
     Ok(())
 }
 
-pub fn read(day: Day) -> Result<Output, AocCommandError> {
-    let puzzle_path = get_puzzle_path(day);
+pub fn read(puzzle: PuzzleId) -> Result<Output, AocCommandError> {
+    let puzzle_path = get_puzzle_path(puzzle);
 
     let args = build_args(
         "read",
@@ -43,15 +54,27 @@ pub fn read(day: Day) -> Result<Output, AocCommandError> {
             "--puzzle-file".into(),
             puzzle_path,
         ],
-        day,
+        puzzle,
     );
 
     call_aoc_cli(&args)
 }
 
-pub fn download(day: Day) -> Result<Output, AocCommandError> {
-    let input_path = get_input_path(day);
-    let puzzle_path = get_puzzle_path(day);
+pub fn download(puzzle: PuzzleId) -> Result<Output, AocCommandError> {
+    // never request a puzzle from the server before it's released.
+    if !puzzle.is_unlocked() {
+        return Err(AocCommandError::PuzzleLocked);
+    }
+
+    let input_path = get_input_path(puzzle);
+    let puzzle_path = get_puzzle_path(puzzle);
+
+    // aoc-cli does not create missing parent directories, e.g. for a new year.
+    for path in [&input_path, &puzzle_path] {
+        if let Some(parent) = Path::new(path).parent() {
+            let _ = fs::create_dir_all(parent);
+        }
+    }
 
     let args = build_args(
         "download",
@@ -62,7 +85,7 @@ pub fn download(day: Day) -> Result<Output, AocCommandError> {
             "--puzzle-file".into(),
             puzzle_path.to_string(),
         ],
-        day,
+        puzzle,
     );
 
     let output = call_aoc_cli(&args)?;
@@ -72,50 +95,78 @@ pub fn download(day: Day) -> Result<Output, AocCommandError> {
     Ok(output)
 }
 
-pub fn submit(day: Day, part: u8, result: &str) -> Result<Output, AocCommandError> {
+/// Downloads only the puzzle description, e.g. to fetch part two once part one is solved.
+pub fn download_puzzle(puzzle: PuzzleId) -> Result<Output, AocCommandError> {
+    let puzzle_path = get_puzzle_path(puzzle);
+    if let Some(parent) = Path::new(&puzzle_path).parent() {
+        let _ = fs::create_dir_all(parent);
+    }
+
+    let args = build_args(
+        "download",
+        &[
+            "--overwrite".into(),
+            "--puzzle-only".into(),
+            "--puzzle-file".into(),
+            puzzle_path,
+        ],
+        puzzle,
+    );
+
+    call_aoc_cli(&args)
+}
+
+/// Submits an answer and reads the outcome from the response of the server.
+/// A response that can't be read is printed as it is.
+pub fn submit(
+    puzzle: PuzzleId,
+    part: Part,
+    result: &str,
+) -> Result<SubmitOutcome, AocCommandError> {
     // workaround: the argument order is inverted for submit.
-    let mut args = build_args("submit", &[], day);
+    let mut args = build_args("submit", &[], puzzle);
     args.push(part.to_string());
     args.push(result.to_string());
-    call_aoc_cli(&args)
-}
 
-fn get_input_path(day: Day) -> String {
-    format!("data/inputs/{day}.txt")
+    let output = spawn_aoc_cli(&args, Stdio::piped())?;
+
+    let response = String::from_utf8_lossy(&output.stdout);
+    if let Some(outcome) = SubmitOutcome::from_response(&response) {
+        return Ok(outcome);
+    }
+
+    print!("{response}");
+    if output.status.success() {
+        Err(AocCommandError::UnknownResponse(output))
+    } else {
+        Err(AocCommandError::BadExitStatus(output))
+    }
 }
 
-fn get_puzzle_path(day: Day) -> String {
-    format!("data/puzzles/{day}.md")
+pub(crate) fn get_input_path(puzzle: PuzzleId) -> String {
+    format!("data/inputs/{}/{}.txt", puzzle.year, puzzle.day)
 }
 
-fn get_year() -> Option<u16> {
-    match std::env::var("AOC_YEAR") {
-        Ok(x) => x.parse().ok().or(None),
-        Err(_) => None,
-    }
+pub(crate) fn get_puzzle_path(puzzle: PuzzleId) -> String {
+    format!("data/puzzles/{}/{}.md", puzzle.year, puzzle.day)
 }
 
-fn build_args(command: &str, args: &[String], day: Day) -> Vec<String> {
+fn build_args(command: &str, args: &[String], puzzle: PuzzleId) -> Vec<String> {
     let mut cmd_args = args.to_vec();
 
-    if let Some(year) = get_year() {
-        cmd_args.push("--year".into());
-        cmd_args.push(year.to_string());
-    }
-
-    cmd_args.append(&mut vec!["--day".into(), day.to_string(), command.into()]);
+    cmd_args.append(&mut vec![
+        "--year".into(),
+        puzzle.year.to_string(),
+        "--day".into(),
+        puzzle.day.to_string(),
+        command.into(),
+    ]);
 
     cmd_args
 }
 
 fn call_aoc_cli(args: &[String]) -> Result<Output, AocCommandError> {
-    // println!("Calling >aoc with: {}", args.join(" "));
-    let output = Command::new("aoc")
-        .args(args)
-        .stdout(Stdio::inherit())
-        .stderr(Stdio::inherit())
-        .output()
-        .map_err(|_| AocCommandError::CommandNotCallable)?;
+    let output = spawn_aoc_cli(args, Stdio::inherit())?;
 
     if output.status.success() {
         Ok(output)
@@ -123,3 +174,176 @@ fn call_aoc_cli(args: &[String]) -> Result<Output, AocCommandError> {
         Err(AocCommandError::BadExitStatus(output))
     }
 }
+
+/// Runs aoc-cli regardless of its exit status. Its output is captured if `stdout` is piped, e.g.
+/// to read the response to a submission.
+fn spawn_aoc_cli(args: &[String], stdout: Stdio) -> Result<Output, AocCommandError> {
+    // println!("Calling >aoc with: {}", args.join(" "));
+    Command::new("aoc")
+        .args(args)
+        .stdout(stdout)
+        .stderr(Stdio::inherit())
+        .output()
+        .map_err(|_| AocCommandError::CommandNotCallable)
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Whether a wrong answer is too high or too low.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AnswerHint {
+    TooHigh,
+    TooLow,
+}
+
+/// The outcome of submitting an answer, as read from the response of the server.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SubmitOutcome {
+    Correct,
+    /// The answer is wrong, the server may hint whether it's too high or too low.
+    Incorrect {
+        hint: Option<AnswerHint>,
+    },
+    /// The answer was not checked, because the previous submission was too recent.
+    Wait {
+        seconds: Option<u64>,
+    },
+    /// The part was already solved, or the part before it is not.
+    AlreadySolved,
+}
+
+static WAIT_TIME: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"You have (?:(\d+)m ?)?(?:(\d+)s)? left to wait").unwrap());
+
+impl SubmitOutcome {
+    /// Reads the outcome from the response to a submission, `None` if it has none of the known messages.
+    /// The response may be wrapped over several lines, e.g. by aoc-cli.
+    pub fn from_response(response: &str) -> Option<Self> {
+        let response = response.split_whitespace().collect::<Vec<_>>().join(" ");
+
+        if response.contains("That's the right answer") {
+            Some(Self::Correct)
+        } else if response.contains("You gave an answer too recently") {
+            let seconds = WAIT_TIME.captures(&response).and_then(|captures| {
+                let read = |i| captures.get(i).map_or(Some(0), |m| m.as_str().parse().ok());
+                let (minutes, seconds): (u64, u64) = (read(1)?, read(2)?);
+                Some(minutes * 60 + seconds)
+            });
+            Some(Self::Wait { seconds })
+        } else if response.contains("That's not the right answer") {
+            let hint = if response.contains("your answer is too high") {
+                Some(AnswerHint::TooHigh)
+            } else if response.contains("your answer is too low") {
+                Some(AnswerHint::TooLow)
+            } else {
+                None
+            };
+            Some(Self::Incorrect { hint })
+        } else if response.contains("You don't seem to be solving the right level") {
+            Some(Self::AlreadySolved)
+        } else {
+            None
+        }
+    }
+}
+
+impl Display for SubmitOutcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Correct => write!(f, "⭐ That's the right answer!"),
+            Self::Incorrect { hint: None } => write!(f, "✖ That's not the right answer."),
+            Self::Incorrect {
+                hint: Some(AnswerHint::TooHigh),
+            } => write!(f, "✖ That's not the right answer, it is too high."),
+            Self::Incorrect {
+                hint: Some(AnswerHint::TooLow),
+            } => write!(f, "✖ That's not the right answer, it is too low."),
+            Self::Wait { seconds: None } => {
+                write!(
+                    f,
+                    "⏳ You gave an answer too recently, please wait before trying again."
+                )
+            }
+            Self::Wait {
+                seconds: Some(seconds),
+            } => {
+                let wait = match (seconds / 60, seconds % 60) {
+                    (0, seconds) => format!("{seconds}s"),
+                    (minutes, seconds) => format!("{minutes}m {seconds}s"),
+                };
+                write!(
+                    f,
+                    "⏳ You gave an answer too recently, please wait {wait} before trying again."
+                )
+            }
+            Self::AlreadySolved => write!(
+                f,
+                "This part is already solved, or the part before it is not solved yet."
+            ),
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{AnswerHint, SubmitOutcome};
+
+    #[test]
+    fn reads_outcomes_from_responses() {
+        let wrong = "That's not the right answer; your answer is too\nlow. If you're stuck, ...";
+        assert_eq!(
+            SubmitOutcome::from_response(wrong),
+            Some(SubmitOutcome::Incorrect {
+                hint: Some(AnswerHint::TooLow)
+            })
+        );
+        assert_eq!(
+            SubmitOutcome::from_response("That's not the right answer. If you're stuck, ..."),
+            Some(SubmitOutcome::Incorrect { hint: None })
+        );
+        assert_eq!(
+            SubmitOutcome::from_response("That's the right answer! You are one gold star closer."),
+            Some(SubmitOutcome::Correct)
+        );
+        assert_eq!(
+            SubmitOutcome::from_response(
+                "You gave an answer too recently; [...] You have 1m 3s left to wait."
+            ),
+            Some(SubmitOutcome::Wait { seconds: Some(63) })
+        );
+        assert_eq!(
+            SubmitOutcome::from_response(
+                "You gave an answer too recently. You have 42s left to wait."
+            ),
+            Some(SubmitOutcome::Wait { seconds: Some(42) })
+        );
+        assert_eq!(
+            SubmitOutcome::from_response(
+                "You don't seem to be solving the right level.  Did you already complete it?"
+            ),
+            Some(SubmitOutcome::AlreadySolved)
+        );
+        assert_eq!(SubmitOutcome::from_response("Internal Server Error"), None);
+    }
+
+    #[test]
+    fn formats_outcomes() {
+        assert_eq!(
+            SubmitOutcome::Incorrect {
+                hint: Some(AnswerHint::TooHigh)
+            }
+            .to_string(),
+            "✖ That's not the right answer, it is too high."
+        );
+        assert_eq!(
+            SubmitOutcome::Wait { seconds: Some(63) }.to_string(),
+            "⏳ You gave an answer too recently, please wait 1m 3s before trying again."
+        );
+        assert_eq!(
+            SubmitOutcome::Wait { seconds: Some(42) }.to_string(),
+            "⏳ You gave an answer too recently, please wait 42s before trying again."
+        );
+    }
+}
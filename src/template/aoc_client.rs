@@ -0,0 +1,492 @@
+/// A built-in client for the Advent of Code website, an alternative to the "aoc-cli" command-line.
+/// It is selected by setting `AOC_CLIENT` to `native` in `.cargo/config.toml`.
+use std::error::Error;
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::LazyLock;
+use std::time::Duration;
+use std::{env, fs, io, process};
+
+use regex::Regex;
+
+use crate::template::aoc_cli::{SubmitOutcome, get_input_path, get_puzzle_path};
+use crate::template::{Part, PuzzleId};
+
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Advent of Code asks automated tools to identify themselves.
+/// Users add their own contact with `AOC_USER_AGENT`, see [`AocClient::from_env`].
+const USER_AGENT: &str = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
+
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+/// The tool used to talk to the Advent of Code website.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum AocBackend {
+    /// The external `aoc` binary, see [`crate::template::aoc_cli`].
+    #[default]
+    AocCli,
+    /// The built-in [`AocClient`].
+    Native,
+}
+
+impl AocBackend {
+    /// Reads the backend from the `AOC_CLIENT` environment variable, aoc-cli is used if it's not set.
+    pub fn from_env() -> Result<Self, AocBackendFromStrError> {
+        env::var("AOC_CLIENT").map_or(Ok(Self::default()), |value| value.parse())
+    }
+}
+
+impl FromStr for AocBackend {
+    type Err = AocBackendFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "aoc-cli" => Ok(Self::AocCli),
+            "native" => Ok(Self::Native),
+            _ => Err(AocBackendFromStrError),
+        }
+    }
+}
+
+/// An error which can be returned when parsing an [`AocBackend`].
+#[derive(Debug)]
+pub struct AocBackendFromStrError;
+
+impl Error for AocBackendFromStrError {}
+
+impl Display for AocBackendFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "expecting either \"aoc-cli\" or \"native\"")
+    }
+}
+
+/// Same as [`AocBackend::from_env`], exits the process if `AOC_CLIENT` is invalid.
+pub fn backend_or_exit() -> AocBackend {
+    AocBackend::from_env().unwrap_or_else(|e| {
+        eprintln!("AOC_CLIENT: {e}");
+        process::exit(1);
+    })
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[derive(Debug)]
+pub enum AocClientError {
+    MissingSession(PathBuf),
+    PuzzleLocked,
+    BadStatus(u16),
+    Request(String),
+    UnknownResponse,
+    Io(io::Error),
+}
+
+impl Display for AocClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AocClientError::MissingSession(path) => write!(
+                f,
+                "no session cookie found, set AOC_SESSION or write it to \"{}\".",
+                path.display()
+            ),
+            AocClientError::PuzzleLocked => write!(f, "the puzzle has not been unlocked yet."),
+            AocClientError::BadStatus(400) => {
+                write!(
+                    f,
+                    "the server rejected the request, is the session cookie valid?"
+                )
+            }
+            AocClientError::BadStatus(404) => {
+                write!(
+                    f,
+                    "the server could not find the puzzle, is it unlocked yet?"
+                )
+            }
+            AocClientError::BadStatus(status) => {
+                write!(f, "the server responded with status {status}.")
+            }
+            AocClientError::Request(e) => write!(f, "the request failed: {e}"),
+            AocClientError::UnknownResponse => {
+                write!(f, "the response of the server could not be read.")
+            }
+            AocClientError::Io(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl Error for AocClientError {}
+
+impl From<ureq::Error> for AocClientError {
+    fn from(e: ureq::Error) -> Self {
+        match e {
+            ureq::Error::Status(status, _) => AocClientError::BadStatus(status),
+            ureq::Error::Transport(e) => AocClientError::Request(e.to_string()),
+        }
+    }
+}
+
+impl From<io::Error> for AocClientError {
+    fn from(e: io::Error) -> Self {
+        AocClientError::Io(e)
+    }
+}
+
+/// Downloads inputs and puzzle descriptions and submits answers on behalf of a logged in user.
+pub struct AocClient {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl AocClient {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+            agent: build_agent(USER_AGENT),
+        }
+    }
+
+    /// Replaces the user agent of the requests, e.g. to add a way to contact you.
+    pub fn with_user_agent(mut self, user_agent: &str) -> Self {
+        self.agent = build_agent(user_agent);
+        self
+    }
+
+    /// Creates a client from the environment:
+    ///  - the session cookie is read from `AOC_SESSION`, or else from the file at `AOC_SESSION_FILE`,
+    ///    which defaults to the `.adventofcode.session` file in the home directory that aoc-cli uses.
+    ///  - the website can be replaced with `AOC_BASE_URL`, e.g. to point at a mirror.
+    ///  - the user agent can be replaced with `AOC_USER_AGENT`, e.g. with your repository and email.
+    pub fn from_env() -> Result<Self, AocClientError> {
+        let base_url = env::var("AOC_BASE_URL").unwrap_or_else(|_| DEFAULT_BASE_URL.into());
+
+        let session = match env::var("AOC_SESSION") {
+            Ok(session) if !session.trim().is_empty() => session,
+            _ => {
+                let path = session_file_path();
+                match fs::read_to_string(&path) {
+                    Ok(session) if !session.trim().is_empty() => session,
+                    _ => return Err(AocClientError::MissingSession(path)),
+                }
+            }
+        };
+
+        let client = Self::new(&base_url, &session);
+        match env::var("AOC_USER_AGENT") {
+            Ok(user_agent) if !user_agent.trim().is_empty() => {
+                Ok(client.with_user_agent(user_agent.trim()))
+            }
+            _ => Ok(client),
+        }
+    }
+
+    /// Fetches the puzzle input of a day.
+    pub fn input(&self, puzzle: PuzzleId) -> Result<String, AocClientError> {
+        let response = self
+            .request("GET", &format!("{}/input", day_path(puzzle)))
+            .call()?;
+        Ok(response.into_string()?)
+    }
+
+    /// Fetches the description of a day as markdown. It holds part two once part one is solved.
+    pub fn puzzle(&self, puzzle: PuzzleId) -> Result<String, AocClientError> {
+        let response = self.request("GET", &day_path(puzzle)).call()?;
+        Ok(articles_to_markdown(&response.into_string()?))
+    }
+
+    /// Submits the answer to a part and returns the server's response as text,
+    /// e.g. "That's the right answer! [...]".
+    pub fn submit(
+        &self,
+        puzzle: PuzzleId,
+        part: Part,
+        answer: &str,
+    ) -> Result<String, AocClientError> {
+        let level = part.to_string();
+        let response = self
+            .request("POST", &format!("{}/answer", day_path(puzzle)))
+            .send_form(&[("level", &level), ("answer", answer)])?;
+        Ok(articles_to_markdown(&response.into_string()?))
+    }
+
+    fn request(&self, method: &str, path: &str) -> ureq::Request {
+        self.agent
+            .request(method, &format!("{}{path}", self.base_url))
+            .set("Cookie", &format!("session={}", self.session))
+    }
+}
+
+fn build_agent(user_agent: &str) -> ureq::Agent {
+    ureq::AgentBuilder::new()
+        .user_agent(user_agent)
+        .timeout(TIMEOUT)
+        .build()
+}
+
+/// The path of a day on the website, e.g. `/2025/day/9`.
+fn day_path(puzzle: PuzzleId) -> String {
+    format!("/{}/day/{}", puzzle.year, puzzle.day.into_inner())
+}
+
+fn session_file_path() -> PathBuf {
+    if let Ok(path) = env::var("AOC_SESSION_FILE") {
+        return PathBuf::from(path);
+    }
+
+    let home = env::var("HOME")
+        .or_else(|_| env::var("USERPROFILE"))
+        .unwrap_or_default();
+    Path::new(&home).join(".adventofcode.session")
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Same as [`crate::template::aoc_cli::download`], using the built-in client.
+pub fn download(puzzle: PuzzleId) -> Result<(), AocClientError> {
+    // never request a puzzle from the server before it's released.
+    if !puzzle.is_unlocked() {
+        return Err(AocClientError::PuzzleLocked);
+    }
+
+    let client = AocClient::from_env()?;
+    let input_path = get_input_path(puzzle);
+    let puzzle_path = get_puzzle_path(puzzle);
+
+    write_file(&input_path, &client.input(puzzle)?)?;
+    write_file(&puzzle_path, &client.puzzle(puzzle)?)?;
+
+    println!("🎄 Successfully wrote input to \"{}\".", &input_path);
+    println!("🎄 Successfully wrote puzzle to \"{}\".", &puzzle_path);
+    Ok(())
+}
+
+/// Same as [`crate::template::aoc_cli::read`], using the built-in client.
+/// The description is stored in the puzzle file, so part two is available once it is unlocked.
+pub fn read(puzzle: PuzzleId) -> Result<(), AocClientError> {
+    let client = AocClient::from_env()?;
+    let description = client.puzzle(puzzle)?;
+    write_file(&get_puzzle_path(puzzle), &description)?;
+    print!("{description}");
+    Ok(())
+}
+
+/// Same as [`crate::template::aoc_cli::download_puzzle`], using the built-in client.
+pub fn download_puzzle(puzzle: PuzzleId) -> Result<(), AocClientError> {
+    let description = AocClient::from_env()?.puzzle(puzzle)?;
+    write_file(&get_puzzle_path(puzzle), &description)?;
+    Ok(())
+}
+
+/// Same as [`crate::template::aoc_cli::submit`], using the built-in client.
+/// A response that can't be read is printed as it is.
+pub fn submit(puzzle: PuzzleId, part: Part, answer: &str) -> Result<SubmitOutcome, AocClientError> {
+    let response = AocClient::from_env()?.submit(puzzle, part, answer)?;
+
+    SubmitOutcome::from_response(&response).ok_or_else(|| {
+        print!("{response}");
+        AocClientError::UnknownResponse
+    })
+}
+
+fn write_file(path: &str, contents: &str) -> io::Result<()> {
+    if let Some(parent) = Path::new(path).parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, contents)
+}
+
+/* -------------------------------------------------------------------------- */
+
+static ARTICLE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?s)<article[^>]*>(.*?)</article>").unwrap());
+static TAG: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"<(/?)([a-zA-Z0-9]+)[^>]*>").unwrap());
+static BLANK_LINES: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\n{3,}").unwrap());
+
+/// Converts the `<article>` elements of a page to markdown, keeping the markup that puzzles use.
+fn articles_to_markdown(html: &str) -> String {
+    let articles: Vec<&str> = ARTICLE
+        .captures_iter(html)
+        .map(|captures| captures.get(1).unwrap().as_str())
+        .collect();
+
+    let mut markdown = String::new();
+    for article in articles {
+        markdown.push_str(&html_to_markdown(article));
+        markdown.push_str("\n\n");
+    }
+
+    let markdown = BLANK_LINES.replace_all(markdown.trim(), "\n\n");
+    if markdown.is_empty() {
+        String::new()
+    } else {
+        format!("{markdown}\n")
+    }
+}
+
+fn html_to_markdown(html: &str) -> String {
+    let mut markdown = String::new();
+    let mut in_pre = false;
+    let mut in_code = false;
+    let mut last = 0;
+
+    for captures in TAG.captures_iter(html) {
+        let tag = captures.get(0).unwrap();
+        markdown.push_str(&unescape(&html[last..tag.start()]));
+        last = tag.end();
+
+        let is_closing = &captures[1] == "/";
+        match (&captures[2], is_closing) {
+            ("h2", false) => markdown.push_str("## "),
+            ("h2" | "p", true) => markdown.push_str("\n\n"),
+            ("pre", false) => {
+                in_pre = true;
+                markdown.push_str("```\n");
+            }
+            ("pre", true) => {
+                in_pre = false;
+                markdown.push_str("```\n\n");
+            }
+            ("code", _) => {
+                in_code = !is_closing;
+                if !in_pre {
+                    markdown.push('`');
+                }
+            }
+            ("em", _) if !in_code => markdown.push('*'),
+            ("li", false) => markdown.push_str("- "),
+            ("ul", true) => markdown.push('\n'),
+            _ => {}
+        }
+    }
+
+    markdown.push_str(&unescape(&html[last..]));
+    markdown
+}
+
+fn unescape(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::thread::{self, JoinHandle};
+
+    use super::{AocClient, AocClientError, articles_to_markdown};
+    use crate::template::Part;
+
+    const PUZZLE: &str = r#"<html><body><main>
+<article class="day-desc"><h2>--- Day 9: Movie Theater ---</h2><p>Find the <em>largest</em> rectangle in <code>tiles</code>:</p>
+<pre><code>7,1
+11,1
+</code></pre>
+<ul>
+<li>Use <code><em>x &lt; y</em></code> &amp; check.</li>
+</ul>
+</article>
+<p>Your puzzle answer was <code>42</code>.</p>
+</main></body></html>"#;
+
+    /// A stand-in for the website, answering each request with the next response.
+    /// Returns the base url and a handle to the raw requests it received.
+    fn serve(responses: Vec<(u16, &'static str)>) -> (String, JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = thread::spawn(move || {
+            let mut requests = vec![];
+
+            for (status, body) in responses {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+                let mut request = String::new();
+                let mut content_length = 0;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if let Some(length) = line.to_lowercase().strip_prefix("content-length:") {
+                        content_length = length.trim().parse().unwrap();
+                    }
+                    if line.trim_end().is_empty() {
+                        break;
+                    }
+                    request.push_str(&line);
+                }
+
+                let mut body_bytes = vec![0; content_length];
+                reader.read_exact(&mut body_bytes).unwrap();
+                request.push_str(&String::from_utf8(body_bytes).unwrap());
+                requests.push(request);
+
+                write!(
+                    stream,
+                    "HTTP/1.1 {status} Status\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                )
+                .unwrap();
+            }
+
+            requests
+        });
+
+        (base_url, handle)
+    }
+
+    #[test]
+    fn downloads_input_and_puzzle() {
+        let (base_url, server) = serve(vec![(200, "1,2\n3,4\n"), (200, PUZZLE)]);
+        let client = AocClient::new(&format!("{base_url}/"), "abc123\n");
+        let puzzle = crate::puzzle!(2025, 9);
+
+        assert_eq!(client.input(puzzle).unwrap(), "1,2\n3,4\n");
+        assert_eq!(
+            client.puzzle(puzzle).unwrap(),
+            "## --- Day 9: Movie Theater ---\n\nFind the *largest* rectangle in `tiles`:\n\n```\n7,1\n11,1\n```\n\n- Use `x < y` & check.\n"
+        );
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("GET /2025/day/9/input HTTP/1.1\r\n"));
+        assert!(requests[0].contains("Cookie: session=abc123\r\n"));
+        assert!(requests[0].contains(&format!("User-Agent: {}\r\n", super::USER_AGENT)));
+        assert!(requests[1].starts_with("GET /2025/day/9 HTTP/1.1\r\n"));
+    }
+
+    #[test]
+    fn submits_answers() {
+        let response = "<main><article><p>That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer.</p></article></main>";
+        let (base_url, server) = serve(vec![(200, response), (400, "")]);
+        let client = AocClient::new(&base_url, "abc123").with_user_agent("me@example.com");
+        let puzzle = crate::puzzle!(2024, 1);
+
+        assert_eq!(
+            client.submit(puzzle, Part::One, "a b&c").unwrap(),
+            "That's the right answer!  You are one gold star closer.\n"
+        );
+        assert!(matches!(
+            client.submit(puzzle, Part::Two, "7"),
+            Err(AocClientError::BadStatus(400))
+        ));
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("POST /2024/day/1/answer HTTP/1.1\r\n"));
+        assert!(requests[0].contains("User-Agent: me@example.com\r\n"));
+        assert!(requests[0].ends_with("\r\nlevel=1&answer=a+b%26c"));
+        assert!(requests[1].ends_with("\r\nlevel=2&answer=7"));
+    }
+
+    #[test]
+    fn ignores_pages_without_articles() {
+        assert_eq!(articles_to_markdown("<html><p>Not found</p></html>"), "");
+    }
+}
@@ -0,0 +1,172 @@
+use std::error::Error;
+use std::time::Duration;
+use std::{cmp, env};
+
+use crate::template::bench_stats::parse_nanos;
+
+/// Controls how long and how often a part is run when benching.
+///
+/// Defaults are read from the `AOC_BENCH_TIME`, `AOC_MIN_SAMPLES` and `AOC_MAX_SAMPLES`
+/// environment variables, which can be set in `.cargo/config.toml`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BenchConfig {
+    /// Approximate time spent on collecting samples.
+    pub time: Duration,
+    pub min_samples: u32,
+    pub max_samples: u32,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        Self {
+            time: Duration::from_secs(1),
+            min_samples: 10,
+            max_samples: 10_000,
+        }
+    }
+}
+
+impl BenchConfig {
+    /// Reads the configuration from the environment, falling back to the defaults.
+    pub fn from_env() -> Result<Self, Box<dyn Error>> {
+        let mut config = Self::default();
+
+        if let Ok(time) = env::var("AOC_BENCH_TIME") {
+            config.time = parse_duration(&time).map_err(|e| format!("AOC_BENCH_TIME: {e}"))?;
+        }
+        if let Ok(n) = env::var("AOC_MIN_SAMPLES") {
+            config.min_samples = n.parse().map_err(|e| format!("AOC_MIN_SAMPLES: {e}"))?;
+        }
+        if let Ok(n) = env::var("AOC_MAX_SAMPLES") {
+            config.max_samples = n.parse().map_err(|e| format!("AOC_MAX_SAMPLES: {e}"))?;
+        }
+
+        Ok(config)
+    }
+
+    /// Reads the configuration from the environment and overrides it with the
+    /// `--bench-time`, `--min-samples` and `--max-samples` arguments.
+    pub fn from_args(args: &mut pico_args::Arguments) -> Result<Self, Box<dyn Error>> {
+        let mut config = Self::from_env()?;
+
+        if let Some(time) = args.opt_value_from_fn("--bench-time", parse_duration)? {
+            config.time = time;
+        }
+        if let Some(n) = args.opt_value_from_str("--min-samples")? {
+            config.min_samples = n;
+        }
+        if let Some(n) = args.opt_value_from_str("--max-samples")? {
+            config.max_samples = n;
+        }
+
+        if config.min_samples == 0 {
+            return Err("`--min-samples` has to be at least 1.".into());
+        }
+        if config.min_samples > config.max_samples {
+            return Err(format!(
+                "`--min-samples` ({}) is larger than `--max-samples` ({}).",
+                config.min_samples, config.max_samples
+            )
+            .into());
+        }
+
+        Ok(config)
+    }
+
+    /// The arguments that pass this configuration on to a solution binary.
+    pub fn to_args(&self) -> Vec<String> {
+        vec![
+            "--bench-time".into(),
+            format!("{}ns", self.time.as_nanos()),
+            "--min-samples".into(),
+            self.min_samples.to_string(),
+            "--max-samples".into(),
+            self.max_samples.to_string(),
+        ]
+    }
+
+    /// The number of samples to collect for a part that takes `base_time` per run.
+    pub fn samples(&self, base_time: Duration) -> u32 {
+        let samples = self.time.as_nanos() / cmp::max(base_time.as_nanos(), 10);
+        u32::try_from(samples)
+            .unwrap_or(u32::MAX)
+            .clamp(self.min_samples, self.max_samples)
+    }
+}
+
+/// Parses a duration like `500ms` or `2.5s`. Plain numbers are read as seconds.
+pub(crate) fn parse_duration(s: &str) -> Result<Duration, String> {
+    let nanos = s
+        .parse::<f64>()
+        .map(|secs| secs * 1_000_000_000.0)
+        .ok()
+        .or_else(|| parse_nanos(s))
+        .filter(|nanos| nanos.is_finite() && *nanos >= 0.0)
+        .ok_or_else(|| format!("expecting a duration like `500ms` or `2s`, found `{s}`."))?;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    Ok(Duration::from_nanos(nanos.round() as u64))
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::ffi::OsString;
+    use std::time::Duration;
+
+    use super::{BenchConfig, parse_duration};
+
+    fn from_args(args: &[&str]) -> Result<BenchConfig, Box<dyn std::error::Error>> {
+        let mut args = pico_args::Arguments::from_vec(args.iter().map(OsString::from).collect());
+        BenchConfig::from_args(&mut args)
+    }
+
+    #[test]
+    fn parses_durations() {
+        assert_eq!(parse_duration("2"), Ok(Duration::from_secs(2)));
+        assert_eq!(parse_duration("0.5s"), Ok(Duration::from_millis(500)));
+        assert_eq!(parse_duration("250ms"), Ok(Duration::from_millis(250)));
+        assert_eq!(parse_duration("30µs"), Ok(Duration::from_micros(30)));
+        assert!(parse_duration("-1s").is_err());
+        assert!(parse_duration("soon").is_err());
+    }
+
+    #[test]
+    fn parses_arguments() {
+        let config = from_args(&[
+            "--bench-time",
+            "30s",
+            "--min-samples",
+            "1",
+            "--max-samples",
+            "3",
+        ])
+        .unwrap();
+        assert_eq!(config.time, Duration::from_secs(30));
+        assert_eq!(config.max_samples, 3);
+
+        assert!(from_args(&["--min-samples", "0"]).is_err());
+        assert!(from_args(&["--min-samples", "5", "--max-samples", "4"]).is_err());
+    }
+
+    #[test]
+    fn round_trips_through_arguments() {
+        let config = BenchConfig {
+            time: Duration::from_micros(1500),
+            min_samples: 2,
+            max_samples: 50,
+        };
+        let args: Vec<String> = config.to_args();
+        let parsed = from_args(&args.iter().map(String::as_str).collect::<Vec<_>>()).unwrap();
+        assert_eq!(parsed, config);
+    }
+
+    #[test]
+    fn computes_samples() {
+        let config = BenchConfig::default();
+        assert_eq!(config.samples(Duration::from_millis(1)), 1000);
+        assert_eq!(config.samples(Duration::from_secs(5)), 10);
+        assert_eq!(config.samples(Duration::ZERO), 10_000);
+    }
+}
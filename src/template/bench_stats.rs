@@ -0,0 +1,255 @@
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+use std::time::Duration;
+
+/// Summary statistics of the samples of a benchmark. All figures are in nanoseconds.
+///
+/// Outliers are rejected with Tukey's fences before computing the figures.
+///
+/// # Display
+/// This value displays as a single line listing all figures.
+///
+/// ```
+/// # use std::time::Duration;
+/// # use advent_of_code::template::BenchStats;
+/// let samples: Vec<Duration> = [10, 11, 12, 11, 10, 500].map(Duration::from_micros).to_vec();
+/// let stats = BenchStats::from_samples(&samples);
+/// assert_eq!(stats.outliers, 1);
+/// assert_eq!(stats.max, 12_000.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BenchStats {
+    /// Number of measured samples, including rejected outliers.
+    pub samples: u64,
+    /// Number of samples rejected as outliers.
+    pub outliers: u64,
+    pub mean: f64,
+    pub min: f64,
+    pub median: f64,
+    pub p95: f64,
+    pub max: f64,
+    pub stddev: f64,
+}
+
+impl BenchStats {
+    /// Computes the statistics of a non-empty set of samples.
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        #[allow(clippy::cast_precision_loss)]
+        let mut sorted: Vec<f64> = samples.iter().map(|d| d.as_nanos() as f64).collect();
+        sorted.sort_unstable_by(f64::total_cmp);
+
+        // reject samples outside of Tukey's fences, e.g. caused by context switches.
+        let (q1, q3) = (quantile(&sorted, 0.25), quantile(&sorted, 0.75));
+        let fence = 1.5 * (q3 - q1);
+        let kept: Vec<f64> = sorted
+            .iter()
+            .copied()
+            .filter(|x| (q1 - fence..=q3 + fence).contains(x))
+            .collect();
+
+        #[allow(clippy::cast_precision_loss)]
+        let n = kept.len() as f64;
+        let mean = kept.iter().sum::<f64>() / n;
+        let variance = if kept.len() > 1 {
+            kept.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n - 1.0)
+        } else {
+            0.0
+        };
+
+        Self {
+            samples: sorted.len() as u64,
+            outliers: (sorted.len() - kept.len()) as u64,
+            mean,
+            min: kept[0],
+            median: quantile(&kept, 0.5),
+            p95: quantile(&kept, 0.95),
+            max: kept[kept.len() - 1],
+            stddev: variance.sqrt(),
+        }
+    }
+
+    /// Returns the value of one of the figures.
+    pub fn figure(&self, figure: BenchFigure) -> f64 {
+        match figure {
+            BenchFigure::Mean => self.mean,
+            BenchFigure::Min => self.min,
+            BenchFigure::Median => self.median,
+            BenchFigure::P95 => self.p95,
+            BenchFigure::Max => self.max,
+        }
+    }
+}
+
+/// Linearly interpolated quantile of sorted values.
+fn quantile(sorted: &[f64], q: f64) -> f64 {
+    #[allow(clippy::cast_precision_loss)]
+    let rank = q * (sorted.len() - 1) as f64;
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let (lower, upper) = (rank.floor() as usize, rank.ceil() as usize);
+    sorted[lower] + (sorted[upper] - sorted[lower]) * (rank - rank.floor())
+}
+
+/// Formats nanoseconds like a [`Duration`], e.g. `1.5ms`.
+pub(crate) fn format_nanos(nanos: f64) -> String {
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let duration = Duration::from_nanos(nanos.round() as u64);
+    format!("{duration:.1?}")
+}
+
+/// Parses a duration formatted by [`format_nanos`] back to nanoseconds.
+pub(crate) fn parse_nanos(s: &str) -> Option<f64> {
+    // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
+    let s = s.trim();
+    let (value, factor) = if let Some(value) = s.strip_suffix("ns") {
+        (value, 1.0)
+    } else if let Some(value) = s.strip_suffix("µs") {
+        (value, 1_000.0)
+    } else if let Some(value) = s.strip_suffix("ms") {
+        (value, 1_000_000.0)
+    } else {
+        (s.strip_suffix('s')?, 1_000_000_000.0)
+    };
+    value.parse::<f64>().ok().map(|x| x * factor)
+}
+
+impl Display for BenchStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "mean {} · min {} · median {} · p95 {} · max {} · stddev {} · {} samples · {} outliers",
+            format_nanos(self.mean),
+            format_nanos(self.min),
+            format_nanos(self.median),
+            format_nanos(self.p95),
+            format_nanos(self.max),
+            format_nanos(self.stddev),
+            self.samples,
+            self.outliers,
+        )
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// One of the figures of [`BenchStats`], e.g. to select what's shown in the readme.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BenchFigure {
+    #[default]
+    Mean,
+    Min,
+    Median,
+    P95,
+    Max,
+}
+
+impl Display for BenchFigure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Mean => "mean",
+            Self::Min => "min",
+            Self::Median => "median",
+            Self::P95 => "p95",
+            Self::Max => "max",
+        })
+    }
+}
+
+impl FromStr for BenchFigure {
+    type Err = BenchFigureFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "mean" => Ok(Self::Mean),
+            "min" => Ok(Self::Min),
+            "median" => Ok(Self::Median),
+            "p95" => Ok(Self::P95),
+            "max" => Ok(Self::Max),
+            _ => Err(BenchFigureFromStrError),
+        }
+    }
+}
+
+/// An error which can be returned when parsing a [`BenchFigure`].
+#[derive(Debug)]
+pub struct BenchFigureFromStrError;
+
+impl Error for BenchFigureFromStrError {}
+
+impl Display for BenchFigureFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting one of `mean`, `min`, `median`, `p95` or `max`")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{BenchFigure, BenchStats, format_nanos, parse_nanos};
+
+    #[test]
+    fn computes_statistics() {
+        let samples: Vec<Duration> = (1..=100).map(Duration::from_nanos).collect();
+        let stats = BenchStats::from_samples(&samples);
+        assert_eq!(stats.samples, 100);
+        assert_eq!(stats.outliers, 0);
+        assert_eq!(stats.min, 1.0);
+        assert_eq!(stats.max, 100.0);
+        assert_eq!(stats.mean, 50.5);
+        assert_eq!(stats.median, 50.5);
+        assert!((stats.p95 - 95.05).abs() < 1e-9);
+        assert!((stats.stddev - 29.011_491).abs() < 1e-6);
+        assert_eq!(stats.figure(BenchFigure::Median), 50.5);
+    }
+
+    #[test]
+    fn rejects_outliers() {
+        let mut samples: Vec<Duration> = vec![Duration::from_micros(100); 20];
+        samples.push(Duration::from_millis(50));
+        samples.push(Duration::from_nanos(1));
+        let stats = BenchStats::from_samples(&samples);
+        assert_eq!(stats.samples, 22);
+        assert_eq!(stats.outliers, 2);
+        assert_eq!(stats.max, 100_000.0);
+        assert_eq!(stats.stddev, 0.0);
+    }
+
+    #[test]
+    fn handles_single_samples() {
+        let stats = BenchStats::from_samples(&[Duration::from_millis(3)]);
+        assert_eq!(stats.mean, 3_000_000.0);
+        assert_eq!(stats.p95, 3_000_000.0);
+        assert_eq!(stats.stddev, 0.0);
+    }
+
+    #[test]
+    fn formats_and_parses_durations() {
+        assert_eq!(format_nanos(74.13), "74.0ns");
+        assert_eq!(format_nanos(1_500_000.0), "1.5ms");
+        assert_eq!(parse_nanos("74.13ns"), Some(74.13));
+        assert_eq!(parse_nanos("2.5µs"), Some(2_500.0));
+        assert_eq!(parse_nanos("1.5ms"), Some(1_500_000.0));
+        assert_eq!(parse_nanos("2s"), Some(2_000_000_000.0));
+        assert_eq!(parse_nanos("ms"), None);
+    }
+
+    #[test]
+    fn displays_on_a_single_line() {
+        let samples: Vec<Duration> = (1..=10).map(Duration::from_micros).collect();
+        let stats = BenchStats::from_samples(&samples);
+        assert_eq!(
+            stats.to_string(),
+            "mean 5.5µs · min 1.0µs · median 5.5µs · p95 9.6µs · max 10.0µs · stddev 3.0µs · 10 samples · 0 outliers"
+        );
+    }
+
+    #[test]
+    fn parses_figures() {
+        assert_eq!("p95".parse::<BenchFigure>().unwrap(), BenchFigure::P95);
+        assert_eq!(BenchFigure::default().to_string(), "mean");
+        assert!("average".parse::<BenchFigure>().is_err());
+    }
+}
@@ -0,0 +1,122 @@
+use std::time::{Duration, SystemTime};
+
+use crate::template::{AllDays, Day, PuzzleId, Year};
+
+/// The first year in which the event was shortened to 12 days.
+const SHORT_EVENT_SINCE: u16 = 2025;
+
+/// Puzzles unlock at midnight on the server, which runs at UTC-5.
+const UNLOCK_HOUR_UTC: u64 = 5;
+
+/// The event calendar of a single year, i.e. which days have a puzzle and when they unlock.
+///
+/// ```
+/// # use advent_of_code::{day, year};
+/// let calendar = year!(2025).calendar();
+/// assert_eq!(calendar.day_count(), 12);
+/// assert!(!calendar.contains(day!(13)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Calendar {
+    year: Year,
+}
+
+impl Calendar {
+    pub const fn new(year: Year) -> Self {
+        Self { year }
+    }
+
+    /// Returns the number of puzzles of the event.
+    /// Events up to 2024 had 25 puzzles, later events have 12.
+    pub const fn day_count(&self) -> u8 {
+        if self.year.into_inner() >= SHORT_EVENT_SINCE {
+            12
+        } else {
+            25
+        }
+    }
+
+    /// Returns `true` if the event has a puzzle on the given [`Day`].
+    pub const fn contains(&self, day: Day) -> bool {
+        day.into_inner() <= self.day_count()
+    }
+
+    /// An iterator that yields every day of the event.
+    pub fn days(&self) -> AllDays {
+        AllDays::new(self.year)
+    }
+
+    /// An iterator that yields every puzzle of the event.
+    pub fn puzzles(&self) -> impl Iterator<Item = PuzzleId> + use<> {
+        let year = self.year;
+        self.days().map(move |day| PuzzleId { year, day })
+    }
+
+    /// Returns the point in time at which the puzzle for the given [`Day`] unlocks.
+    pub fn unlock_time(&self, day: Day) -> SystemTime {
+        let days = days_from_civil(
+            i64::from(self.year.into_inner()),
+            12,
+            day.into_inner().into(),
+        );
+        // NOTE: events take place after 1970, so the day count is positive.
+        let secs = days.unsigned_abs() * 86_400 + UNLOCK_HOUR_UTC * 3600;
+        SystemTime::UNIX_EPOCH + Duration::from_secs(secs)
+    }
+}
+
+impl Year {
+    /// Returns the event [`Calendar`] of this year.
+    pub const fn calendar(self) -> Calendar {
+        Calendar::new(self)
+    }
+}
+
+/// Number of days since 1970-01-01 for a date in the proleptic gregorian calendar.
+/// see: http://howardhinnant.github.io/date_algorithms.html#days_from_civil
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::{Duration, SystemTime};
+
+    use crate::{day, puzzle, year};
+
+    #[test]
+    fn knows_event_length() {
+        assert_eq!(year!(2015).calendar().day_count(), 25);
+        assert_eq!(year!(2024).calendar().day_count(), 25);
+        assert_eq!(year!(2025).calendar().day_count(), 12);
+        assert!(year!(2024).calendar().contains(day!(25)));
+        assert!(!year!(2025).calendar().contains(day!(13)));
+    }
+
+    #[test]
+    fn computes_unlock_time() {
+        // 2024-12-01T05:00:00Z
+        assert_eq!(
+            year!(2024).calendar().unlock_time(day!(1)),
+            SystemTime::UNIX_EPOCH + Duration::from_secs(1_733_029_200)
+        );
+        // 2025-12-12T05:00:00Z
+        assert_eq!(
+            year!(2025).calendar().unlock_time(day!(12)),
+            SystemTime::UNIX_EPOCH + Duration::from_secs(1_765_515_600)
+        );
+    }
+
+    #[test]
+    fn knows_released_puzzles() {
+        assert!(puzzle!(2015, 1).is_unlocked());
+        assert!(!puzzle!(9999, 1).is_unlocked());
+    }
+}
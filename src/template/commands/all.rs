@@ -1,5 +1,48 @@
-use crate::template::{all_days, run_multi::run_multi};
+use std::process;
 
-pub fn handle(is_release: bool) {
-    run_multi(&all_days().collect(), is_release, false);
+use crate::template::runner::Solution;
+use crate::template::{ANSI_BOLD, ANSI_RESET, PuzzleId, RunLimits, run_multi::run_multi};
+
+/// Runs the given puzzles. With `check`, exits with a non-zero status if any answer
+/// differs from the one recorded in `data/answers.json`, or any day did not complete.
+/// With more than one job, days run concurrently.
+pub fn handle(
+    puzzles: &[PuzzleId],
+    is_release: bool,
+    check: bool,
+    limits: RunLimits,
+    verbosity: u8,
+    jobs: usize,
+    solutions: &[Solution],
+) {
+    let summary = run_multi(
+        &puzzles.iter().copied().collect(),
+        is_release,
+        None,
+        limits,
+        verbosity,
+        false,
+        jobs,
+        solutions,
+    );
+
+    if !check {
+        return;
+    }
+
+    println!();
+    if summary.mismatches.is_empty() && summary.failures.is_empty() {
+        println!("{ANSI_BOLD}All known answers match.{ANSI_RESET}");
+    } else {
+        for (puzzle, status) in &summary.failures {
+            eprintln!("✘ {} Day {} {status}.", puzzle.year, puzzle.day);
+        }
+        for (puzzle, part) in &summary.mismatches {
+            eprintln!(
+                "✘ {} Day {} part {part} does not match its known answer.",
+                puzzle.year, puzzle.day
+            );
+        }
+        process::exit(1);
+    }
 }
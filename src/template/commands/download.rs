@@ -1,7 +1,23 @@
-use crate::template::{Day, aoc_cli};
+use crate::template::aoc_client::{self, AocBackend, AocClientError};
+use crate::template::{PuzzleId, aoc_cli};
 use std::process;
 
-pub fn handle(day: Day) {
+pub fn handle(puzzle: PuzzleId) {
+    if aoc_client::backend_or_exit() == AocBackend::Native {
+        match aoc_client::download(puzzle) {
+            Ok(()) => {}
+            Err(AocClientError::PuzzleLocked) => {
+                eprintln!("Puzzle {puzzle} has not been unlocked yet, refusing to download it.");
+                process::exit(1);
+            }
+            Err(e) => {
+                eprintln!("failed to download puzzle {puzzle}: {e}");
+                process::exit(1);
+            }
+        }
+        return;
+    }
+
     if aoc_cli::check().is_err() {
         eprintln!(
             "command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it."
@@ -9,8 +25,15 @@ pub fn handle(day: Day) {
         process::exit(1);
     }
 
-    if let Err(e) = aoc_cli::download(day) {
-        eprintln!("failed to call aoc-cli: {e}");
-        process::exit(1);
-    };
+    match aoc_cli::download(puzzle) {
+        Ok(_) => {}
+        Err(aoc_cli::AocCommandError::PuzzleLocked) => {
+            eprintln!("Puzzle {puzzle} has not been unlocked yet, refusing to download it.");
+            process::exit(1);
+        }
+        Err(e) => {
+            eprintln!("failed to call aoc-cli: {e}");
+            process::exit(1);
+        }
+    }
 }
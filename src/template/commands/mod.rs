@@ -3,4 +3,7 @@ pub mod download;
 pub mod read;
 pub mod scaffold;
 pub mod solve;
+pub mod test_day;
 pub mod time;
+#[cfg(feature = "today")]
+pub mod today;
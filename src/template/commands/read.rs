@@ -1,8 +1,17 @@
 use std::process;
 
-use crate::template::{Day, aoc_cli};
+use crate::template::aoc_client::{self, AocBackend};
+use crate::template::{PuzzleId, aoc_cli};
+
+pub fn handle(puzzle: PuzzleId) {
+    if aoc_client::backend_or_exit() == AocBackend::Native {
+        if let Err(e) = aoc_client::read(puzzle) {
+            eprintln!("failed to read puzzle {puzzle}: {e}");
+            process::exit(1);
+        }
+        return;
+    }
 
-pub fn handle(day: Day) {
     if aoc_cli::check().is_err() {
         eprintln!(
             "command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it."
@@ -10,7 +19,7 @@ pub fn handle(day: Day) {
         process::exit(1);
     }
 
-    if let Err(e) = aoc_cli::read(day) {
+    if let Err(e) = aoc_cli::read(puzzle) {
         eprintln!("failed to call aoc-cli: {e}");
         process::exit(1);
     };
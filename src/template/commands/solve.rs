@@ -1,9 +1,21 @@
 use std::process::{Command, Stdio};
 
-use crate::template::Day;
+use crate::template::watch::watch;
+use crate::template::{BenchConfig, Part, PuzzleId, VisualizeMode};
 
-pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
-    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), day.to_string()];
+#[allow(clippy::too_many_arguments)]
+pub fn handle(
+    puzzle: PuzzleId,
+    release: bool,
+    dhat: bool,
+    submit_part: Option<Part>,
+    accept_part: Option<Part>,
+    bench_config: Option<BenchConfig>,
+    verbosity: u8,
+    visualize: Option<VisualizeMode>,
+    watch_files: bool,
+) {
+    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), puzzle.bin_name()];
 
     if dhat {
         cmd_args.extend([
@@ -23,12 +35,49 @@ pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
         cmd_args.push(submit_part.to_string());
     }
 
+    if let Some(bench_config) = bench_config {
+        cmd_args.push("--bench".to_string());
+        cmd_args.extend(bench_config.to_args());
+    }
+
+    if let Some(accept_part) = accept_part {
+        cmd_args.push("--accept".to_string());
+        cmd_args.push(accept_part.to_string());
+    }
+
+    cmd_args.extend((0..verbosity).map(|_| "--verbose".to_string()));
+
+    if let Some(visualize) = visualize {
+        cmd_args.push("--visualize".to_string());
+        cmd_args.push(visualize.to_string());
+    }
+
+    if !watch_files {
+        run_cargo(&cmd_args);
+        return;
+    }
+
+    // examples are quick to check, the puzzle input only runs once they succeed.
+    let mut example_args = cmd_args.clone();
+    example_args.push("--example".to_string());
+
+    watch(puzzle, || {
+        println!("Running the examples:");
+        if run_cargo(&example_args) {
+            println!("\nRunning the puzzle input:");
+            run_cargo(&cmd_args);
+        }
+    });
+}
+
+/// Runs cargo with the given arguments, returns `true` if it succeeded.
+fn run_cargo(args: &[String]) -> bool {
     let mut cmd = Command::new("cargo")
-        .args(&cmd_args)
+        .args(args)
         .stdout(Stdio::inherit())
         .stderr(Stdio::inherit())
         .spawn()
         .unwrap();
 
-    cmd.wait().unwrap();
+    cmd.wait().unwrap().success()
 }
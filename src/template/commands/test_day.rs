@@ -0,0 +1,28 @@
+use std::process::{self, Command, Stdio};
+
+use crate::template::PuzzleId;
+use crate::template::watch::watch;
+
+/// Runs the tests of a day's solution, and with `watch_files` again after every change to it.
+pub fn handle(puzzle: PuzzleId, watch_files: bool) {
+    if watch_files {
+        watch(puzzle, || {
+            run_tests(puzzle);
+        });
+    }
+
+    if !run_tests(puzzle) {
+        process::exit(1);
+    }
+}
+
+fn run_tests(puzzle: PuzzleId) -> bool {
+    let mut cmd = Command::new("cargo")
+        .args(["test", "--bin", &puzzle.bin_name()])
+        .stdout(Stdio::inherit())
+        .stderr(Stdio::inherit())
+        .spawn()
+        .unwrap();
+
+    cmd.wait().unwrap().success()
+}
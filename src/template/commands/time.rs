@@ -1,34 +1,63 @@
-use std::collections::HashSet;
+use std::{collections::HashSet, process};
 
 use crate::template::run_multi::run_multi;
+use crate::template::runner::Solution;
 use crate::template::timings::Timings;
-use crate::template::{Day, all_days, readme_benchmarks};
+use crate::template::{BenchConfig, BenchFigure, PuzzleId, RunLimits, Year, readme_benchmarks};
 
-pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
-    let stored_timings = Timings::read_from_file();
+#[allow(clippy::too_many_arguments)]
+pub fn handle(
+    year: Year,
+    puzzles: Option<Vec<PuzzleId>>,
+    run_all: bool,
+    store: bool,
+    figure: BenchFigure,
+    bench_config: BenchConfig,
+    limits: RunLimits,
+    memory: bool,
+    solutions: &[Solution],
+) {
+    // the stored timings are merged with the new ones, an unreadable file must not be replaced.
+    let stored_timings = Timings::read_from_file().unwrap_or_else(|e| {
+        eprintln!("Not benching: the stored timings are unknown, {e}");
+        process::exit(1);
+    });
 
-    let days_to_run = day.map_or_else(
+    let puzzles_to_run: HashSet<PuzzleId> = puzzles.map_or_else(
         || {
+            let puzzles = year.calendar().puzzles();
             if run_all {
-                all_days().collect()
+                puzzles.collect()
             } else {
                 // when the `--all` flag is not set, filter out days that are fully benched.
-                all_days()
-                    .filter(|day| !stored_timings.is_day_complete(*day))
+                puzzles
+                    .filter(|puzzle| !stored_timings.is_day_complete(*puzzle))
                     .collect()
             }
         },
-        |day| HashSet::from([day]),
+        |puzzles| puzzles.into_iter().collect(),
     );
 
-    let timings = run_multi(&days_to_run, true, true).unwrap();
+    // days are benched one at a time, so that they don't compete for the CPU.
+    let timings = run_multi(
+        &puzzles_to_run,
+        true,
+        Some(bench_config),
+        limits,
+        0,
+        memory,
+        1,
+        solutions,
+    )
+    .timings
+    .unwrap();
 
     if store {
         let merged_timings = stored_timings.merge(&timings);
         merged_timings.store_file().unwrap();
 
         println!();
-        match readme_benchmarks::update(merged_timings) {
+        match readme_benchmarks::update(merged_timings, figure) {
             Ok(()) => {
                 println!("Stored updated benchmarks.");
             }
@@ -0,0 +1,72 @@
+use std::io::{Write, stdout};
+use std::process;
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+use crate::template::commands::{download, read, scaffold};
+use crate::template::{ANSI_BOLD, ANSI_RESET, PuzzleId};
+
+pub fn handle(wait: bool) {
+    let puzzle = if wait {
+        match PuzzleId::next_locked() {
+            Some(puzzle) => {
+                wait_for_unlock(puzzle);
+                puzzle
+            }
+            None => {
+                eprintln!(
+                    "There is no puzzle left to unlock in this year's event. \
+                    Please use `scaffold` with a specific day."
+                );
+                process::exit(1)
+            }
+        }
+    } else {
+        match PuzzleId::today() {
+            Some(puzzle) => puzzle,
+            None => {
+                eprintln!(
+                    "`today` command can only be run on the days of an event. \
+                    Please use `scaffold` with a specific day or `today --wait`."
+                );
+                process::exit(1)
+            }
+        }
+    };
+
+    scaffold::handle(puzzle, false);
+    download::handle(puzzle);
+    read::handle(puzzle);
+}
+
+/// Blocks until the puzzle is released, printing a countdown in the meantime.
+fn wait_for_unlock(puzzle: PuzzleId) {
+    let unlock_time = puzzle.unlock_time();
+    let mut stdout = stdout();
+
+    while let Ok(remaining) = unlock_time.duration_since(SystemTime::now()) {
+        let secs = remaining.as_secs();
+        print!(
+            "\r⏳ {} Day {} unlocks in {ANSI_BOLD}{:02}:{:02}:{:02}{ANSI_RESET} ",
+            puzzle.year,
+            puzzle.day,
+            secs / 3600,
+            (secs / 60) % 60,
+            secs % 60
+        );
+        let _ = stdout.flush();
+
+        // NOTE: wake up on the full second so the countdown ends exactly at the unlock time.
+        let sleep_for = remaining - Duration::from_secs(secs);
+        thread::sleep(if sleep_for.is_zero() {
+            Duration::from_secs(1).min(remaining)
+        } else {
+            sleep_for
+        });
+    }
+
+    println!(
+        "\r🎄 {} Day {} is unlocked!                    ",
+        puzzle.year, puzzle.day
+    );
+}
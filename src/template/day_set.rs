@@ -0,0 +1,210 @@
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::template::{Day, PuzzleId, Year};
+
+/// An inclusive range of days. An open end extends the range to the last day of an event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct DayRange {
+    start: Day,
+    end: Option<Day>,
+}
+
+impl DayRange {
+    fn contains(&self, day: Day) -> bool {
+        day >= self.start && self.end.is_none_or(|end| day <= end)
+    }
+}
+
+/// A set of days, parsed from a comma-separated list of days and day ranges.
+///
+/// Ranges are inclusive. A range without an end (e.g. `10-`) extends to the last day
+/// of an event, a range without a start (e.g. `-5`) begins on the first day.
+///
+/// ```
+/// # use advent_of_code::{template::DaySet, day};
+/// let days: DaySet = "1-3,8,10-".parse().unwrap();
+/// assert!(days.contains(day!(2)));
+/// assert!(!days.contains(day!(9)));
+/// assert!(days.contains(day!(25)));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DaySet {
+    ranges: Vec<DayRange>,
+}
+
+impl DaySet {
+    /// A set containing every day of an event.
+    pub fn all() -> Self {
+        Self {
+            ranges: vec![DayRange {
+                start: crate::day!(1),
+                end: None,
+            }],
+        }
+    }
+
+    /// Returns `true` if the set contains the given [`Day`].
+    pub fn contains(&self, day: Day) -> bool {
+        self.ranges.iter().any(|range| range.contains(day))
+    }
+
+    /// Returns the puzzles of the given year's event that are part of the set, in order.
+    /// Days that are not part of the event calendar are skipped.
+    pub fn puzzles(&self, year: Year) -> impl Iterator<Item = PuzzleId> + use<'_> {
+        year.calendar()
+            .puzzles()
+            .filter(|puzzle| self.contains(puzzle.day))
+    }
+}
+
+impl Display for DaySet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let ranges: Vec<String> = self
+            .ranges
+            .iter()
+            .map(|range| match range.end {
+                Some(end) if end == range.start => range.start.into_inner().to_string(),
+                Some(end) => format!("{}-{}", range.start.into_inner(), end.into_inner()),
+                None => format!("{}-", range.start.into_inner()),
+            })
+            .collect();
+        f.write_str(&ranges.join(","))
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl FromStr for DaySet {
+    type Err = DaySetFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let ranges = s
+            .split(',')
+            .map(|item| {
+                let item = item.trim();
+                let range = match item.split_once('-') {
+                    Some((start, end)) => DayRange {
+                        start: if start.is_empty() {
+                            crate::day!(1)
+                        } else {
+                            start.parse().map_err(|_| DaySetFromStrError)?
+                        },
+                        end: if end.is_empty() {
+                            None
+                        } else {
+                            Some(end.parse().map_err(|_| DaySetFromStrError)?)
+                        },
+                    },
+                    None => {
+                        let day = item.parse().map_err(|_| DaySetFromStrError)?;
+                        DayRange {
+                            start: day,
+                            end: Some(day),
+                        }
+                    }
+                };
+
+                if range.end.is_some_and(|end| end < range.start) {
+                    return Err(DaySetFromStrError);
+                }
+
+                Ok(range)
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { ranges })
+    }
+}
+
+/// An error which can be returned when parsing a [`DaySet`].
+#[derive(Debug)]
+pub struct DaySetFromStrError;
+
+impl Error for DaySetFromStrError {}
+
+impl Display for DaySetFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting day numbers or ranges of them, e.g. `1-5,8,10-`")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::DaySet;
+    use crate::{day, puzzle, year};
+
+    #[test]
+    fn parses_single_days() {
+        let days: DaySet = "8".parse().unwrap();
+        assert!(days.contains(day!(8)));
+        assert!(!days.contains(day!(7)));
+        assert!(!days.contains(day!(9)));
+    }
+
+    #[test]
+    fn parses_ranges_and_lists() {
+        let days: DaySet = "1-5,8,10-".parse().unwrap();
+        let puzzles: Vec<_> = days.puzzles(year!(2025)).collect();
+        assert_eq!(
+            puzzles,
+            vec![
+                puzzle!(2025, 1),
+                puzzle!(2025, 2),
+                puzzle!(2025, 3),
+                puzzle!(2025, 4),
+                puzzle!(2025, 5),
+                puzzle!(2025, 8),
+                puzzle!(2025, 10),
+                puzzle!(2025, 11),
+                puzzle!(2025, 12),
+            ]
+        );
+    }
+
+    #[test]
+    fn parses_open_start() {
+        let days: DaySet = "-3".parse().unwrap();
+        assert_eq!(days.puzzles(year!(2024)).count(), 3);
+    }
+
+    #[test]
+    fn yields_sorted_unique_puzzles() {
+        let days: DaySet = "5,1-3,2".parse().unwrap();
+        let puzzles: Vec<_> = days.puzzles(year!(2024)).collect();
+        assert_eq!(
+            puzzles,
+            vec![
+                puzzle!(2024, 1),
+                puzzle!(2024, 2),
+                puzzle!(2024, 3),
+                puzzle!(2024, 5)
+            ]
+        );
+    }
+
+    #[test]
+    fn respects_event_calendar() {
+        assert_eq!(DaySet::all().puzzles(year!(2024)).count(), 25);
+        assert_eq!(DaySet::all().puzzles(year!(2025)).count(), 12);
+    }
+
+    #[test]
+    fn displays_as_expression() {
+        let days: DaySet = "1-5, 8,10-".parse().unwrap();
+        assert_eq!(days.to_string(), "1-5,8,10-");
+    }
+
+    #[test]
+    fn rejects_invalid_expressions() {
+        assert!("".parse::<DaySet>().is_err());
+        assert!("0".parse::<DaySet>().is_err());
+        assert!("26".parse::<DaySet>().is_err());
+        assert!("5-1".parse::<DaySet>().is_err());
+        assert!("1-2-3".parse::<DaySet>().is_err());
+        assert!("1,,2".parse::<DaySet>().is_err());
+    }
+}
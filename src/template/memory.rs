@@ -0,0 +1,171 @@
+/// Heap usage of solutions, counted by a global allocator with the `count-allocations` feature.
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::fmt::Display;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+static CURRENT_BYTES: AtomicU64 = AtomicU64::new(0);
+static PEAK_BYTES: AtomicU64 = AtomicU64::new(0);
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+
+/// NOTE: `dhat-heap` brings its own global allocator, only one can be active.
+#[cfg(all(feature = "count-allocations", not(feature = "dhat-heap")))]
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// Wraps the system allocator, counting allocations and the bytes in use.
+pub struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc(layout) };
+        if !ptr.is_null() {
+            record_allocation(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc_zeroed(layout) };
+        if !ptr.is_null() {
+            record_allocation(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) };
+        CURRENT_BYTES.fetch_sub(layout.size() as u64, Ordering::Relaxed);
+    }
+
+    /// A reallocation counts as an allocation of the new size.
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = unsafe { System.realloc(ptr, layout, new_size) };
+        if !new_ptr.is_null() {
+            CURRENT_BYTES.fetch_sub(layout.size() as u64, Ordering::Relaxed);
+            record_allocation(new_size);
+        }
+        new_ptr
+    }
+}
+
+fn record_allocation(size: usize) {
+    ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+    let current = CURRENT_BYTES.fetch_add(size as u64, Ordering::Relaxed) + size as u64;
+    PEAK_BYTES.fetch_max(current, Ordering::Relaxed);
+}
+
+/// Returns `true` if heap usage can be measured, i.e. the counting allocator is active.
+pub fn counts_allocations() -> bool {
+    cfg!(all(
+        feature = "count-allocations",
+        not(feature = "dhat-heap")
+    ))
+}
+
+/// The heap usage of a single run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MemoryStats {
+    /// Most bytes allocated at the same time, on top of the memory in use before the run.
+    pub peak_bytes: u64,
+    /// Number of allocations and reallocations.
+    pub allocations: u64,
+}
+
+impl MemoryStats {
+    /// Runs `func` and measures its heap usage. Returns `None` without the counting allocator.
+    pub fn measure<T>(func: impl FnOnce() -> T) -> (T, Option<Self>) {
+        if !counts_allocations() {
+            return (func(), None);
+        }
+
+        let baseline = CURRENT_BYTES.load(Ordering::Relaxed);
+        PEAK_BYTES.store(baseline, Ordering::Relaxed);
+        let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+
+        let result = func();
+
+        let stats = Self {
+            peak_bytes: PEAK_BYTES.load(Ordering::Relaxed) - baseline,
+            allocations: ALLOCATIONS.load(Ordering::Relaxed) - allocations,
+        };
+        (result, Some(stats))
+    }
+}
+
+impl Display for MemoryStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "peak {} · {} allocations",
+            format_bytes(self.peak_bytes),
+            self.allocations
+        )
+    }
+}
+
+/// The heap usage of the steps of a solution, only set when run with `--memory`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MemoryUsage {
+    /// Only set for solutions with a shared `parse` function.
+    pub parse: Option<MemoryStats>,
+    pub part_1: Option<MemoryStats>,
+    pub part_2: Option<MemoryStats>,
+}
+
+impl MemoryUsage {
+    pub fn is_empty(&self) -> bool {
+        self.parse.is_none() && self.part_1.is_none() && self.part_2.is_none()
+    }
+}
+
+/// Formats a number of bytes with binary units, e.g. `1.5 MiB`.
+pub(crate) fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{value:.1} {}", UNITS[unit])
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{MemoryStats, counts_allocations, format_bytes};
+
+    #[test]
+    fn formats_bytes() {
+        assert_eq!(format_bytes(0), "0 B");
+        assert_eq!(format_bytes(1023), "1023 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(3 * 1024 * 1024), "3.0 MiB");
+        assert_eq!(format_bytes(5 << 30), "5.0 GiB");
+    }
+
+    #[test]
+    fn measures_allocations() {
+        let (len, stats) = MemoryStats::measure(|| {
+            let v: Vec<u64> = (0..1000).collect();
+            v.len()
+        });
+        assert_eq!(len, 1000);
+
+        match stats {
+            Some(stats) => {
+                assert!(counts_allocations());
+                assert!(stats.peak_bytes >= 8000);
+                assert!(stats.allocations >= 1);
+            }
+            None => assert!(!counts_allocations()),
+        }
+    }
+}
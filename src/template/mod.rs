@@ -1,68 +1,181 @@
-use std::{env, fs};
+use std::{env, fs, path::PathBuf};
 
 pub mod aoc_cli;
+pub mod aoc_client;
 pub mod commands;
 pub mod runner;
+pub mod solution_args;
+pub mod trace;
 
+pub use answer::*;
+pub use bench_config::*;
+pub use bench_stats::*;
+pub use calendar::*;
 pub use day::*;
+pub use day_set::*;
+pub use memory::*;
+pub use part::*;
+pub use puzzle_id::*;
+pub use run_limits::*;
+pub use visualizer::*;
+pub use year::*;
 
+mod answer;
+mod answers;
+mod bench_config;
+mod bench_stats;
+mod calendar;
 mod day;
+mod day_set;
+mod memory;
+mod part;
+mod puzzle_id;
 mod readme_benchmarks;
+mod report;
+mod run_limits;
 mod run_multi;
+mod submissions;
+#[cfg(feature = "test_lib")]
+mod test_fixtures;
 mod timings;
+mod visualizer;
+mod watch;
+mod year;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
 
-/// Helper function that reads a text file to a string.
-#[must_use]
-pub fn read_file(folder: &str, day: Day) -> String {
+/// Returns the path of a puzzle's data file, e.g. `data/inputs/2025/01.txt` or `data/examples/2025/01-2.txt`.
+pub(crate) fn data_file_path(folder: &str, puzzle: PuzzleId, suffix: Option<&str>) -> PathBuf {
     let cwd = env::current_dir().unwrap();
-    let filepath = cwd.join("data").join(folder).join(format!("{day}.txt"));
-    let f = fs::read_to_string(filepath);
+    let filename = match suffix {
+        Some(suffix) => format!("{}-{suffix}.txt", puzzle.day),
+        None => format!("{}.txt", puzzle.day),
+    };
+    cwd.join("data")
+        .join(folder)
+        .join(puzzle.year.to_string())
+        .join(filename)
+}
+
+/// Helper function that reads a text file to a string. E.g. like `data/inputs/2025/01.txt`.
+#[must_use]
+pub fn read_file(folder: &str, puzzle: PuzzleId) -> String {
+    let f = fs::read_to_string(data_file_path(folder, puzzle, None));
     f.expect("could not open input file")
 }
 
-/// Helper function that reads a text file to string, appending a part suffix. E.g. like `01-2.txt`.
+/// Helper function that reads a text file to string, appending a part suffix. E.g. like `2025/01-2.txt`.
 #[must_use]
-pub fn read_file_part(folder: &str, day: Day, part: u8) -> String {
-    let cwd = env::current_dir().unwrap();
-    let filepath = cwd
-        .join("data")
-        .join(folder)
-        .join(format!("{day}-{part}.txt"));
-    let f = fs::read_to_string(filepath);
+pub fn read_file_part(folder: &str, puzzle: PuzzleId, part: Part) -> String {
+    let f = fs::read_to_string(data_file_path(folder, puzzle, Some(&part.to_string())));
+    f.expect("could not open input file")
+}
+
+/// Helper function that reads the file of a specific part if present (e.g. `2025/01-2.txt`),
+/// and falls back to the file shared by both parts (e.g. `2025/01.txt`) otherwise.
+#[must_use]
+pub fn read_file_for_part(folder: &str, puzzle: PuzzleId, part: Part) -> String {
+    let f = fs::read_to_string(data_file_path_for_part(folder, puzzle, part));
     f.expect("could not open input file")
 }
 
-/// Creates the constant `DAY` and sets up the input and runner for each part.
+/// Returns the path of a part's data file if present, and the path of the file shared by both parts otherwise.
+pub(crate) fn data_file_path_for_part(folder: &str, puzzle: PuzzleId, part: Part) -> PathBuf {
+    let part_path = data_file_path(folder, puzzle, Some(&part.to_string()));
+    if part_path.exists() {
+        part_path
+    } else {
+        data_file_path(folder, puzzle, None)
+    }
+}
+
+/// Creates the constant `PUZZLE` and sets up the input and runner for each part.
 ///
-/// The optional, second parameter (1 or 2) allows you to only run a single part of the solution.
+/// The first two parameters are the year and the day of the puzzle.
+/// The optional, third parameter (1 or 2) allows you to only run a single part of the solution.
+///
+/// Parts take the input as `&str`, `&[u8]` or `String`, and return an `Option` or a `Result` of their answer.
+///
+/// A trailing `parse` parameter runs a shared `fn parse(input: &str) -> T` once and passes
+/// its output to the parts instead of the input. Parts borrow it as `&T`, or take a clone as `T`,
+/// which is made outside of the timed region. Parsing is timed separately from the parts.
 #[macro_export]
 macro_rules! solution {
-    ($day:expr) => {
-        $crate::solution!(@impl $day, [part_one, 1] [part_two, 2]);
+    ($year:expr, $day:expr, 1) => {
+        $crate::solution!(@impl $year, $day, (), [part_one, One]);
+    };
+    ($year:expr, $day:expr, 2) => {
+        $crate::solution!(@impl $year, $day, (), [part_two, Two]);
     };
-    ($day:expr, 1) => {
-        $crate::solution!(@impl $day, [part_one, 1]);
+    ($year:expr, $day:expr) => {
+        $crate::solution!(@impl $year, $day, (), [part_one, One] [part_two, Two]);
     };
-    ($day:expr, 2) => {
-        $crate::solution!(@impl $day, [part_two, 2]);
+    ($year:expr, $day:expr, 1, parse) => {
+        $crate::solution!(@impl $year, $day, (parse), [part_one, One]);
     };
+    ($year:expr, $day:expr, 2, parse) => {
+        $crate::solution!(@impl $year, $day, (parse), [part_two, Two]);
+    };
+    ($year:expr, $day:expr, parse) => {
+        $crate::solution!(@impl $year, $day, (parse), [part_one, One] [part_two, Two]);
+    };
+
+    (@impl $year:expr, $day:expr, $parse:tt, $( [$func:expr, $part:ident] )*) => {
+        /// The current puzzle.
+        const PUZZLE: $crate::template::PuzzleId = $crate::puzzle!($year, $day);
 
-    (@impl $day:expr, $( [$func:expr, $part:expr] )*) => {
-        /// The current day.
-        const DAY: $crate::template::Day = $crate::day!($day);
+        /// The current solution, registered in the main binary.
+        pub const SOLUTION: $crate::template::runner::Solution = $crate::template::runner::Solution {
+            puzzle: PUZZLE,
+            run: |args| $crate::solution!(@run args, $parse, $( [$func, $part] )*),
+        };
 
         #[cfg(feature = "dhat-heap")]
         #[global_allocator]
         static ALLOC: dhat::Alloc = dhat::Alloc;
 
         fn main() {
-            use $crate::template::runner::*;
-            let input = $crate::template::read_file("inputs", DAY);
-            $( run_part($func, &input, DAY, $part); )*
+            let args = $crate::template::solution_args::SolutionArgs::from_env();
+            if let Err(e) = (SOLUTION.run)(&args) {
+                eprintln!("{e}");
+                std::process::exit(1);
+            }
         }
     };
+
+    (@run $args:ident, (), $( [$func:expr, $part:ident] )*) => {{
+        use $crate::template::runner::*;
+        // a panicking part fails the run, after the other part had its turn.
+        let mut result = Ok(());
+        $({
+            let part = $crate::template::Part::$part;
+            if $args.runs(part) {
+                let input = $args.read_input(PUZZLE, part)?;
+                result = result.and(run_part($func, || SolutionInput::from_input(&input), PUZZLE, part, $args));
+            }
+        })*
+        result
+    }};
+
+    (@run $args:ident, ($parse:ident), $( [$func:expr, $part:ident] )*) => {{
+        use $crate::template::runner::*;
+        use $crate::template::Part;
+        // inputs are read up front, so that the parsed value can borrow from them.
+        let inputs: Vec<(Part, String)> = [$( Part::$part ),*]
+            .into_iter()
+            .filter(|part| $args.runs(*part))
+            .map(|part| $args.read_input(PUZZLE, part).map(|input| (part, input)))
+            .collect::<Result<_, _>>()?;
+        let parse = |input| {
+            let parsed = $parse(input);
+            (&parsed).parse_kind().into_parsed(parsed)
+        };
+        run_parsed(parse, &inputs, PUZZLE, $args, |part, parsed| match part {
+            $( Part::$part => run_part($func, || FromParsed::from_parsed(parsed), PUZZLE, part, $args), )*
+            #[allow(unreachable_patterns)]
+            _ => Ok(()),
+        })
+    }};
 }
@@ -0,0 +1,102 @@
+use std::fmt::Display;
+use std::time::SystemTime;
+
+#[cfg(feature = "today")]
+use chrono::{Datelike, FixedOffset, Utc};
+
+use crate::template::{Day, Year};
+
+#[cfg(feature = "today")]
+const SERVER_UTC_OFFSET: i32 = -5;
+
+/// Identifies a single puzzle by the event [`Year`] and the [`Day`] of advent.
+///
+/// All files belonging to a puzzle are namespaced by the year, so that solutions,
+/// inputs and benchmarks of several events can live in the same repository.
+///
+/// # Display
+/// This value displays as `<year>/<day>`.
+///
+/// ```
+/// # use advent_of_code::puzzle;
+/// let puzzle = puzzle!(2024, 8);
+/// assert_eq!(puzzle.to_string(), "2024/08");
+/// assert_eq!(puzzle.bin_name(), "2024_08");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PuzzleId {
+    pub year: Year,
+    pub day: Day,
+}
+
+impl PuzzleId {
+    /// Creates a [`PuzzleId`] if the [`Day`] is part of the year's event calendar,
+    /// returns [`None`] otherwise.
+    pub const fn new(year: Year, day: Day) -> Option<Self> {
+        if !year.calendar().contains(day) {
+            return None;
+        }
+        Some(Self { year, day })
+    }
+
+    /// Returns the point in time at which the puzzle unlocks.
+    pub fn unlock_time(&self) -> SystemTime {
+        self.year.calendar().unlock_time(self.day)
+    }
+
+    /// Returns `true` if the puzzle has been released.
+    pub fn is_unlocked(&self) -> bool {
+        SystemTime::now() >= self.unlock_time()
+    }
+
+    /// The name of the binary target holding the solution, e.g. `2024_08`.
+    #[must_use]
+    pub fn bin_name(&self) -> String {
+        format!("{}_{}", self.year, self.day)
+    }
+}
+
+#[cfg(feature = "today")]
+impl PuzzleId {
+    /// Returns the current puzzle if an event is running, `None` otherwise.
+    pub fn today() -> Option<Self> {
+        let offset = FixedOffset::east_opt(SERVER_UTC_OFFSET * 3600)?;
+        let today = Utc::now().with_timezone(&offset);
+        if today.month() == 12 {
+            Self::new(
+                Year::new(u16::try_from(today.year()).ok()?)?,
+                Day::new(u8::try_from(today.day()).ok()?)?,
+            )
+        } else {
+            None
+        }
+    }
+
+    /// Returns the next puzzle of the current year's event that is not unlocked yet, if any.
+    pub fn next_locked() -> Option<Self> {
+        let offset = FixedOffset::east_opt(SERVER_UTC_OFFSET * 3600)?;
+        let year = Year::new(u16::try_from(Utc::now().with_timezone(&offset).year()).ok()?)?;
+        year.calendar()
+            .puzzles()
+            .find(|puzzle| !puzzle.is_unlocked())
+    }
+}
+
+impl Display for PuzzleId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{}", self.year, self.day)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Creates a [`PuzzleId`] value in a const context.
+#[macro_export]
+macro_rules! puzzle {
+    ($year:expr, $day:expr) => {
+        const {
+            $crate::template::PuzzleId::new($crate::year!($year), $crate::day!($day))
+                .expect("invalid day, not part of the year's event calendar")
+        }
+    };
+}
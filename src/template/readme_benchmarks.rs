@@ -2,8 +2,10 @@
 /// The approach taken is similar to how `aoc-readme-stars` handles this.
 use std::{fs, io};
 
-use crate::template::Day;
+use crate::template::bench_stats::format_nanos;
+use crate::template::memory::format_bytes;
 use crate::template::timings::Timings;
+use crate::template::{BenchFigure, BenchStats, MemoryStats, PuzzleId, RunStatus, Year};
 
 static MARKER: &str = "<!--- benchmarking table --->";
 
@@ -26,8 +28,8 @@ pub struct TablePosition {
 }
 
 #[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+pub fn get_path_for_bin(puzzle: PuzzleId) -> String {
+    format!("./src/bin/{}.rs", puzzle.bin_name())
 }
 
 fn locate_table(readme: &str) -> Result<TablePosition, Error> {
@@ -52,26 +54,92 @@ fn locate_table(readme: &str) -> Result<TablePosition, Error> {
     Ok(TablePosition { pos_start, pos_end })
 }
 
-fn construct_table(prefix: &str, timings: Timings, total_millis: f64) -> String {
+fn construct_table(prefix: &str, timings: Timings, figure: BenchFigure) -> String {
     let header = format!("{prefix} Benchmarks");
+    let total_millis = timings.total_millis(figure);
 
     let mut lines: Vec<String> = vec![
         MARKER.into(),
         header,
         String::new(),
-        "| Day | Part 1 | Part 2 |".into(),
-        "| :---: | :---: | :---:  |".into(),
+        format!("Showing the {figure} execution time of each part."),
     ];
 
-    for timing in timings.data {
-        let path = get_path_for_bin(timing.day);
-        lines.push(format!(
-            "| [Day {}]({}) | `{}` | `{}` |",
-            timing.day.into_inner(),
-            path,
-            timing.part_1.unwrap_or_else(|| "-".into()),
-            timing.part_2.unwrap_or_else(|| "-".into())
-        ));
+    // NOTE: only show a parse column if any solution has a shared `parse` function.
+    let has_parse = timings.data.iter().any(|t| t.parse.is_some());
+    // NOTE: only show memory columns if any solution was timed with `--memory`.
+    let has_memory = timings.data.iter().any(|t| !t.memory.is_empty());
+
+    if has_memory {
+        lines.push(
+            "Memory columns show the peak heap usage and number of allocations of the first run."
+                .into(),
+        );
+    }
+    lines.push(String::new());
+
+    let mut columns = vec!["Day"];
+    if has_parse {
+        columns.push("Parse");
+    }
+    columns.extend(["Part 1", "Part 2"]);
+    if has_memory {
+        if has_parse {
+            columns.push("Parse Memory");
+        }
+        columns.extend(["Part 1 Memory", "Part 2 Memory"]);
+    }
+
+    let alignments = vec![":---:"; columns.len()];
+    lines.push(format!("| {} |", columns.join(" | ")));
+    lines.push(format!("| {}  |", alignments.join(" | ")));
+
+    // NOTE: parts without statistics of days that did not complete show why, e.g. `timed out`.
+    let format_cell = |stats: Option<BenchStats>, status: RunStatus| match stats {
+        Some(stats) => format!("`{}`", format_nanos(stats.figure(figure))),
+        None if status != RunStatus::Completed => format!("*{status}*"),
+        None => "-".into(),
+    };
+
+    let format_memory_cell = |stats: Option<MemoryStats>| match stats {
+        Some(stats) => format!(
+            "`{}` / {}",
+            format_bytes(stats.peak_bytes),
+            stats.allocations
+        ),
+        None => "-".into(),
+    };
+
+    let mut years: Vec<Year> = timings.data.iter().map(|t| t.puzzle.year).collect();
+    years.sort_unstable();
+    years.dedup();
+
+    // NOTE: list every day of an event's calendar, even if it has not been benched yet.
+    for puzzle in years.iter().flat_map(|year| year.calendar().puzzles()) {
+        let label = format!("{} Day {}", puzzle.year, puzzle.day.into_inner());
+
+        let mut cells = match timings.data.iter().find(|t| t.puzzle == puzzle) {
+            Some(timing) => {
+                let mut cells = vec![format!("[{}]({})", label, get_path_for_bin(puzzle))];
+                if has_parse {
+                    cells.push(format_cell(timing.parse, RunStatus::Completed));
+                }
+                cells.push(format_cell(timing.part_1, timing.status));
+                cells.push(format_cell(timing.part_2, timing.status));
+                if has_memory {
+                    if has_parse {
+                        cells.push(format_memory_cell(timing.memory.parse));
+                    }
+                    cells.push(format_memory_cell(timing.memory.part_1));
+                    cells.push(format_memory_cell(timing.memory.part_2));
+                }
+                cells
+            }
+            None => vec![label],
+        };
+        cells.resize(columns.len(), "-".into());
+
+        lines.push(format!("| {} |", cells.join(" | ")));
     }
 
     lines.push(String::new());
@@ -81,18 +149,17 @@ fn construct_table(prefix: &str, timings: Timings, total_millis: f64) -> String
     lines.join("\n")
 }
 
-fn update_content(s: &mut String, timings: Timings, total_millis: f64) -> Result<(), Error> {
+fn update_content(s: &mut String, timings: Timings, figure: BenchFigure) -> Result<(), Error> {
     let positions = locate_table(s)?;
-    let table = construct_table("##", timings, total_millis);
+    let table = construct_table("##", timings, figure);
     s.replace_range(positions.pos_start..positions.pos_end, &table);
     Ok(())
 }
 
-pub fn update(timings: Timings) -> Result<(), Error> {
+pub fn update(timings: Timings, figure: BenchFigure) -> Result<(), Error> {
     let path = "README.md";
     let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
-    let total_millis = timings.total_millis();
-    update_content(&mut readme, timings, total_millis)?;
+    update_content(&mut readme, timings, figure)?;
     fs::write(path, &readme)?;
     Ok(())
 }
@@ -100,59 +167,37 @@ pub fn update(timings: Timings) -> Result<(), Error> {
 #[cfg(feature = "test_lib")]
 mod tests {
     use super::{MARKER, update_content};
-    use crate::{day, template::timings::Timing, template::timings::Timings};
-
-    fn get_mock_timings() -> Timings {
-        Timings {
-            data: vec![
-                Timing {
-                    day: day!(1),
-                    part_1: Some("10ms".into()),
-                    part_2: Some("20ms".into()),
-                    total_nanos: 3e+10,
-                },
-                Timing {
-                    day: day!(2),
-                    part_1: Some("30ms".into()),
-                    part_2: Some("40ms".into()),
-                    total_nanos: 7e+10,
-                },
-                Timing {
-                    day: day!(4),
-                    part_1: Some("40ms".into()),
-                    part_2: Some("50ms".into()),
-                    total_nanos: 9e+10,
-                },
-            ],
-        }
-    }
+    use crate::template::{
+        BenchFigure, MemoryStats, RunStatus,
+        test_fixtures::{mock_stats, mock_timings},
+    };
 
     #[test]
     #[should_panic]
     fn errors_if_marker_not_present() {
         let mut s = "# readme".to_string();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, mock_timings(), BenchFigure::Mean).unwrap();
     }
 
     #[test]
     #[should_panic]
     fn errors_if_too_many_markers_present() {
         let mut s = format!("{} {} {}", MARKER, MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, mock_timings(), BenchFigure::Mean).unwrap();
     }
 
     #[test]
     fn updates_empty_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, mock_timings(), BenchFigure::Mean).unwrap();
         assert_eq!(s.contains("## Benchmarks"), true);
     }
 
     #[test]
     fn updates_existing_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, mock_timings(), BenchFigure::Mean).unwrap();
+        update_content(&mut s, mock_timings(), BenchFigure::Mean).unwrap();
         assert_eq!(s.matches(MARKER).collect::<Vec<&str>>().len(), 2);
         assert_eq!(s.matches("## Benchmarks").collect::<Vec<&str>>().len(), 1);
     }
@@ -160,18 +205,29 @@ mod tests {
     #[test]
     fn format_benchmarks() {
         let mut s = format!("foo\nbar\n{}\n{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, mock_timings(), BenchFigure::Mean).unwrap();
         let expected = [
             "foo",
             "bar",
             "<!--- benchmarking table --->",
             "## Benchmarks",
             "",
+            "Showing the mean execution time of each part.",
+            "",
             "| Day | Part 1 | Part 2 |",
             "| :---: | :---: | :---:  |",
-            "| [Day 1](./src/bin/01.rs) | `10ms` | `20ms` |",
-            "| [Day 2](./src/bin/02.rs) | `30ms` | `40ms` |",
-            "| [Day 4](./src/bin/04.rs) | `40ms` | `50ms` |",
+            "| [2025 Day 1](./src/bin/2025_01.rs) | `10.0ms` | `20.0ms` |",
+            "| [2025 Day 2](./src/bin/2025_02.rs) | `30.0ms` | `40.0ms` |",
+            "| 2025 Day 3 | - | - |",
+            "| [2025 Day 4](./src/bin/2025_04.rs) | `40.0ms` | `50.0ms` |",
+            "| 2025 Day 5 | - | - |",
+            "| 2025 Day 6 | - | - |",
+            "| 2025 Day 7 | - | - |",
+            "| 2025 Day 8 | - | - |",
+            "| 2025 Day 9 | - | - |",
+            "| 2025 Day 10 | - | - |",
+            "| 2025 Day 11 | - | - |",
+            "| 2025 Day 12 | - | - |",
             "",
             "**Total: 190.00ms**",
             "<!--- benchmarking table --->",
@@ -180,4 +236,58 @@ mod tests {
         .join("\n");
         assert_eq!(s, expected);
     }
+
+    #[test]
+    fn formats_selected_figure() {
+        let mut s = format!("{}{}", MARKER, MARKER);
+        update_content(&mut s, mock_timings(), BenchFigure::P95).unwrap();
+        assert!(s.contains("Showing the p95 execution time of each part."));
+        assert!(s.contains("| [2025 Day 1](./src/bin/2025_01.rs) | `20.0ms` | `40.0ms` |"));
+        assert!(s.contains("**Total: 380.00ms**"));
+    }
+
+    #[test]
+    fn formats_parse_column() {
+        let mut timings = mock_timings();
+        timings.data[1].parse = Some(mock_stats(5e6));
+
+        let mut s = format!("{}{}", MARKER, MARKER);
+        update_content(&mut s, timings, BenchFigure::Mean).unwrap();
+        assert!(s.contains("| Day | Parse | Part 1 | Part 2 |"));
+        assert!(s.contains("| [2025 Day 1](./src/bin/2025_01.rs) | - | `10.0ms` | `20.0ms` |"));
+        assert!(
+            s.contains("| [2025 Day 2](./src/bin/2025_02.rs) | `5.0ms` | `30.0ms` | `40.0ms` |")
+        );
+        assert!(s.contains("| 2025 Day 3 | - | - | - |"));
+        assert!(s.contains("**Total: 195.00ms**"));
+    }
+
+    #[test]
+    fn formats_memory_columns() {
+        let mut timings = mock_timings();
+        timings.data[0].memory.part_1 = Some(MemoryStats {
+            peak_bytes: 1536,
+            allocations: 7,
+        });
+
+        let mut s = format!("{}{}", MARKER, MARKER);
+        update_content(&mut s, timings, BenchFigure::Mean).unwrap();
+        assert!(s.contains("| Day | Part 1 | Part 2 | Part 1 Memory | Part 2 Memory |"));
+        assert!(s.contains("| :---: | :---: | :---: | :---: | :---:  |"));
+        assert!(s.contains(
+            "| [2025 Day 1](./src/bin/2025_01.rs) | `10.0ms` | `20.0ms` | `1.5 KiB` / 7 | - |"
+        ));
+        assert!(s.contains("| 2025 Day 3 | - | - | - | - |"));
+    }
+
+    #[test]
+    fn formats_run_status() {
+        let mut timings = mock_timings();
+        timings.data[2].part_2 = None;
+        timings.data[2].status = RunStatus::TimedOut;
+
+        let mut s = format!("{}{}", MARKER, MARKER);
+        update_content(&mut s, timings, BenchFigure::Mean).unwrap();
+        assert!(s.contains("| [2025 Day 4](./src/bin/2025_04.rs) | `40.0ms` | *timed out* |"));
+    }
 }
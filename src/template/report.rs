@@ -0,0 +1,291 @@
+/// Machine-readable report of a solution run, written by the runner with `--report <path>`.
+/// Every step of a run appends one line of JSON to the report.
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::Path,
+    str::FromStr,
+};
+use tinyjson::JsonValue;
+
+use crate::template::{BenchStats, MemoryStats, Part};
+
+/// A step of a solution run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReportStep {
+    /// The shared `parse` function.
+    Parse,
+    Part(Part),
+}
+
+/// The outcome of a single step of a solution run.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReportEntry {
+    pub step: ReportStep,
+    /// The answer a part returned, `None` for parsing and unsolved parts.
+    pub answer: Option<String>,
+    /// Whether the answer matches the known answer, `None` if there is none.
+    pub matches_known: Option<bool>,
+    /// Duration of the first run in nanoseconds.
+    pub nanos: f64,
+    /// Only set when benching.
+    pub stats: Option<BenchStats>,
+    /// Only set when run with `--memory`.
+    pub memory: Option<MemoryStats>,
+    /// The error a part returned instead of an answer.
+    pub error: Option<String>,
+    /// The message and location of a panic of the step.
+    pub panic: Option<String>,
+}
+
+impl ReportEntry {
+    /// An entry for a step that panicked.
+    pub fn panicked(step: ReportStep, panic: &impl Display) -> Self {
+        Self {
+            step,
+            answer: None,
+            matches_known: None,
+            nanos: 0.0,
+            stats: None,
+            memory: None,
+            error: None,
+            panic: Some(panic.to_string()),
+        }
+    }
+
+    /// Appends the entry to the report at `path` as a single line of JSON.
+    pub fn append_to(&self, path: &Path) -> io::Result<()> {
+        let line = JsonValue::from(self)
+            .stringify()
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.to_string()))?;
+
+        let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+        writeln!(file, "{line}")
+    }
+}
+
+/// Reads all entries of a report. A missing report has no entries.
+pub fn read_report(path: &Path) -> Result<Vec<ReportEntry>, String> {
+    let content = match fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(vec![]),
+        Err(e) => return Err(e.to_string()),
+    };
+
+    content
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(ReportEntry::from_str)
+        .collect()
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl ReportStep {
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            Self::Parse => "parse",
+            Self::Part(Part::One) => "part_1",
+            Self::Part(Part::Two) => "part_2",
+        }
+    }
+}
+
+impl From<&ReportEntry> for JsonValue {
+    fn from(value: &ReportEntry) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("step".into(), JsonValue::String(value.step.as_str().into()));
+        map.insert(
+            "answer".into(),
+            value
+                .answer
+                .clone()
+                .map_or(JsonValue::Null, JsonValue::String),
+        );
+        map.insert(
+            "matches_known".into(),
+            value
+                .matches_known
+                .map_or(JsonValue::Null, JsonValue::Boolean),
+        );
+        map.insert("nanos".into(), JsonValue::Number(value.nanos));
+        map.insert(
+            "stats".into(),
+            value
+                .stats
+                .as_ref()
+                .map_or(JsonValue::Null, JsonValue::from),
+        );
+        map.insert(
+            "memory".into(),
+            value
+                .memory
+                .as_ref()
+                .map_or(JsonValue::Null, JsonValue::from),
+        );
+        map.insert(
+            "error".into(),
+            value
+                .error
+                .clone()
+                .map_or(JsonValue::Null, JsonValue::String),
+        );
+        map.insert(
+            "panic".into(),
+            value
+                .panic
+                .clone()
+                .map_or(JsonValue::Null, JsonValue::String),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl FromStr for ReportEntry {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let json = JsonValue::from_str(s).or(Err("Expected report entry to be valid JSON."))?;
+        let json = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected report entry to be a JSON object.")?;
+
+        let step = match json.get("step").and_then(|v| v.get::<String>()) {
+            Some(step) if step == "parse" => ReportStep::Parse,
+            Some(step) if step == "part_1" => ReportStep::Part(Part::One),
+            Some(step) if step == "part_2" => ReportStep::Part(Part::Two),
+            _ => return Err("Expected entry.step to be `parse`, `part_1` or `part_2`.".into()),
+        };
+
+        let answer = match json.get("answer") {
+            Some(JsonValue::String(answer)) => Some(answer.clone()),
+            Some(JsonValue::Null) => None,
+            _ => return Err("Expected entry.answer to be null or a string.".into()),
+        };
+
+        let matches_known = match json.get("matches_known") {
+            Some(JsonValue::Boolean(matches)) => Some(*matches),
+            Some(JsonValue::Null) => None,
+            _ => return Err("Expected entry.matches_known to be null or a boolean.".into()),
+        };
+
+        let nanos = json
+            .get("nanos")
+            .and_then(|v| v.get::<f64>().copied())
+            .ok_or("Expected entry.nanos to be a number.")?;
+
+        let stats = match json.get("stats") {
+            Some(JsonValue::Null) => None,
+            Some(stats) => Some(BenchStats::try_from(stats)?),
+            None => return Err("Expected entry.stats to be null or benchmark statistics.".into()),
+        };
+
+        let memory = match json.get("memory") {
+            Some(JsonValue::Null) => None,
+            Some(memory) => Some(MemoryStats::try_from(memory)?),
+            None => return Err("Expected entry.memory to be null or memory statistics.".into()),
+        };
+
+        let error = match json.get("error") {
+            Some(JsonValue::String(error)) => Some(error.clone()),
+            Some(JsonValue::Null) => None,
+            _ => return Err("Expected entry.error to be null or a string.".into()),
+        };
+
+        let panic = match json.get("panic") {
+            Some(JsonValue::String(panic)) => Some(panic.clone()),
+            Some(JsonValue::Null) => None,
+            _ => return Err("Expected entry.panic to be null or a string.".into()),
+        };
+
+        Ok(ReportEntry {
+            step,
+            answer,
+            matches_known,
+            nanos,
+            stats,
+            memory,
+            error,
+            panic,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{env, fs, process};
+
+    use super::{ReportEntry, ReportStep, read_report};
+    use crate::template::{MemoryStats, Part, test_fixtures::mock_entry};
+
+    #[test]
+    fn round_trips_through_json() {
+        let entry = ReportEntry {
+            answer: Some("#..#\n└ (1.0ms)".into()),
+            matches_known: Some(false),
+            memory: Some(MemoryStats {
+                peak_bytes: 4_096,
+                allocations: 12,
+            }),
+            ..mock_entry(ReportStep::Part(Part::Two), Some(1_000.0))
+        };
+        let line = tinyjson::JsonValue::from(&entry).stringify().unwrap();
+        assert!(!line.contains('\n'));
+        assert_eq!(line.parse::<ReportEntry>().unwrap(), entry);
+
+        let entry = ReportEntry {
+            answer: None,
+            error: Some("line 17: expected 3 comma-separated coordinates".into()),
+            ..mock_entry(ReportStep::Part(Part::One), None)
+        };
+        let line = tinyjson::JsonValue::from(&entry).stringify().unwrap();
+        assert_eq!(line.parse::<ReportEntry>().unwrap(), entry);
+
+        let entry = ReportEntry::panicked(
+            ReportStep::Parse,
+            &"panicked at src/bin/2025_01.rs:8:5: index out of bounds",
+        );
+        let line = tinyjson::JsonValue::from(&entry).stringify().unwrap();
+        assert_eq!(line.parse::<ReportEntry>().unwrap(), entry);
+    }
+
+    #[test]
+    fn rejects_invalid_entries() {
+        assert!("Part 1: 42".parse::<ReportEntry>().is_err());
+        assert!(
+            r#"{"step":"part_3","answer":null,"matches_known":null,"nanos":1,"stats":null,"memory":null,"error":null,"panic":null}"#
+                .parse::<ReportEntry>()
+                .is_err()
+        );
+        assert!(
+            r#"{"step":"parse","answer":null,"matches_known":null,"stats":null,"memory":null,"error":null,"panic":null}"#
+                .parse::<ReportEntry>()
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn appends_entries() {
+        let path = env::temp_dir().join(format!("aoc-report-test-{}.jsonl", process::id()));
+        let _ = fs::remove_file(&path);
+        assert_eq!(read_report(&path).unwrap(), vec![]);
+
+        let entries = [
+            mock_entry(ReportStep::Parse, Some(1_000.0)),
+            mock_entry(ReportStep::Part(Part::One), Some(2_000.0)),
+        ];
+        for entry in &entries {
+            entry.append_to(&path).unwrap();
+        }
+
+        let report = read_report(&path);
+        fs::remove_file(&path).unwrap();
+        assert_eq!(report.unwrap(), entries);
+    }
+}
@@ -0,0 +1,179 @@
+use std::env;
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+use std::time::Duration;
+
+use crate::template::bench_config::parse_duration;
+
+/// Limits for running a solution in its own process, so that a runaway day can't block `cargo all` or `cargo time`.
+///
+/// Defaults are read from the `AOC_TIMEOUT` and `AOC_MEMORY_LIMIT` environment variables.
+/// Without either, no limits apply.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RunLimits {
+    /// Wall-clock time after which a day is killed.
+    pub timeout: Option<Duration>,
+    /// Maximum address space of a day in bytes. Only enforced on Linux.
+    pub memory: Option<u64>,
+}
+
+impl RunLimits {
+    /// Reads the limits from the environment.
+    pub fn from_env() -> Result<Self, Box<dyn Error>> {
+        let mut limits = Self::default();
+
+        if let Ok(timeout) = env::var("AOC_TIMEOUT") {
+            limits.timeout =
+                Some(parse_duration(&timeout).map_err(|e| format!("AOC_TIMEOUT: {e}"))?);
+        }
+        if let Ok(memory) = env::var("AOC_MEMORY_LIMIT") {
+            limits.memory =
+                Some(parse_bytes(&memory).map_err(|e| format!("AOC_MEMORY_LIMIT: {e}"))?);
+        }
+
+        Ok(limits)
+    }
+
+    /// Reads the limits from the environment and overrides them with the
+    /// `--timeout` and `--memory-limit` arguments.
+    pub fn from_args(args: &mut pico_args::Arguments) -> Result<Self, Box<dyn Error>> {
+        let mut limits = Self::from_env()?;
+
+        if let Some(timeout) = args.opt_value_from_fn("--timeout", parse_duration)? {
+            limits.timeout = Some(timeout);
+        }
+        if let Some(memory) = args.opt_value_from_fn("--memory-limit", parse_bytes)? {
+            limits.memory = Some(memory);
+        }
+
+        Ok(limits)
+    }
+
+    /// Returns `true` if any limit is set.
+    pub fn is_limited(&self) -> bool {
+        self.timeout.is_some() || self.memory.is_some()
+    }
+}
+
+/// Parses a size like `512M` or `2G`, with binary units. Plain numbers are read as bytes.
+fn parse_bytes(s: &str) -> Result<u64, String> {
+    let (value, factor) = match s.trim().to_ascii_uppercase() {
+        s if s.ends_with('K') => (s[..s.len() - 1].to_string(), 1 << 10),
+        s if s.ends_with('M') => (s[..s.len() - 1].to_string(), 1 << 20),
+        s if s.ends_with('G') => (s[..s.len() - 1].to_string(), 1 << 30),
+        s => (s, 1),
+    };
+
+    value
+        .parse::<u64>()
+        .ok()
+        .and_then(|value| value.checked_mul(factor))
+        .filter(|bytes| *bytes > 0)
+        .ok_or_else(|| format!("expecting a size like `512M` or `2G`, found `{s}`."))
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// How the run of a day ended.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum RunStatus {
+    #[default]
+    Completed,
+    /// Killed after exceeding the timeout.
+    TimedOut,
+    /// Terminated by a signal, e.g. after failing to allocate memory within the memory limit.
+    Killed,
+    /// Completed, but a part or `parse` panicked.
+    Panicked,
+    /// Could not be run, or its report could not be read.
+    Failed,
+}
+
+impl Display for RunStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Completed => "completed",
+            Self::TimedOut => "timed out",
+            Self::Killed => "killed",
+            Self::Panicked => "panicked",
+            Self::Failed => "failed",
+        })
+    }
+}
+
+impl FromStr for RunStatus {
+    type Err = RunStatusFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "completed" => Ok(Self::Completed),
+            "timed out" => Ok(Self::TimedOut),
+            "killed" => Ok(Self::Killed),
+            "panicked" => Ok(Self::Panicked),
+            "failed" => Ok(Self::Failed),
+            _ => Err(RunStatusFromStrError),
+        }
+    }
+}
+
+/// An error which can be returned when parsing a [`RunStatus`].
+#[derive(Debug)]
+pub struct RunStatusFromStrError;
+
+impl Error for RunStatusFromStrError {}
+
+impl Display for RunStatusFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting one of `completed`, `timed out`, `killed`, `panicked` or `failed`")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::ffi::OsString;
+    use std::time::Duration;
+
+    use super::{RunLimits, RunStatus, parse_bytes};
+
+    #[test]
+    fn parses_sizes() {
+        assert_eq!(parse_bytes("1024"), Ok(1024));
+        assert_eq!(parse_bytes("64k"), Ok(64 * 1024));
+        assert_eq!(parse_bytes("512M"), Ok(512 * 1024 * 1024));
+        assert_eq!(parse_bytes("2G"), Ok(2 * 1024 * 1024 * 1024));
+        assert!(parse_bytes("0").is_err());
+        assert!(parse_bytes("lots").is_err());
+    }
+
+    #[test]
+    fn parses_arguments() {
+        let mut args = pico_args::Arguments::from_vec(
+            ["--timeout", "30", "--memory-limit", "1G"]
+                .iter()
+                .map(OsString::from)
+                .collect(),
+        );
+        let limits = RunLimits::from_args(&mut args).unwrap();
+        assert_eq!(limits.timeout, Some(Duration::from_secs(30)));
+        assert_eq!(limits.memory, Some(1 << 30));
+        assert!(limits.is_limited());
+        assert!(!RunLimits::default().is_limited());
+    }
+
+    #[test]
+    fn round_trips_statuses() {
+        for status in [
+            RunStatus::Completed,
+            RunStatus::TimedOut,
+            RunStatus::Killed,
+            RunStatus::Panicked,
+            RunStatus::Failed,
+        ] {
+            assert_eq!(status.to_string().parse::<RunStatus>().unwrap(), status);
+        }
+        assert!("crashed".parse::<RunStatus>().is_err());
+    }
+}
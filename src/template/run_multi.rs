@@ -1,56 +1,315 @@
-use std::{collections::HashSet, io};
-
-use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET, Day};
+use std::{
+    collections::{HashMap, HashSet},
+    env,
+    fmt::Display,
+    fs, io,
+    path::PathBuf,
+    process,
+    sync::{
+        Mutex, PoisonError,
+        atomic::{AtomicUsize, Ordering},
+        mpsc,
+    },
+    thread,
+};
 
-use super::{
-    all_days,
-    timings::{Timing, Timings},
+use crate::template::{
+    ANSI_BOLD, ANSI_ITALIC, ANSI_RESET, BenchConfig, BenchFigure, Part, PuzzleId, RunLimits,
+    RunStatus, counts_allocations,
 };
 
-pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -> Option<Timings> {
-    let mut timings: Vec<Timing> = Vec::with_capacity(days_to_run.len());
+use super::report::{ReportEntry, read_report};
+use super::runner::Solution;
+use super::solution_args::SolutionArgs;
+use super::timings::{Timing, Timings};
+
+/// The outcome of running several solutions.
+pub struct RunSummary {
+    /// Collected timings, if the solutions were timed.
+    pub timings: Option<Timings>,
+    /// Parts whose answer does not match the one in `data/answers.json`.
+    pub mismatches: Vec<(PuzzleId, Part)>,
+    /// Days that did not complete, e.g. because they exceeded a limit.
+    pub failures: Vec<(PuzzleId, RunStatus)>,
+}
+
+/// Runs the given puzzles. Registered solutions are run in-process, unless `is_release` asks for
+/// an optimized build and the main binary is a debug build. Other solutions are run with `cargo run`.
+/// With limits, every solution is built up front and run in its own process, so it can be stopped.
+/// With more than one job, days run concurrently in their own processes as well, their output is
+/// buffered and printed in day order.
+/// With a `verbosity`, the debug messages of each day are printed after its results.
+/// With `memory`, solutions are run with the counting allocator, which is built into a separate binary if needed.
+#[allow(clippy::too_many_arguments)]
+pub fn run_multi(
+    puzzles_to_run: &HashSet<PuzzleId>,
+    is_release: bool,
+    bench_config: Option<BenchConfig>,
+    limits: RunLimits,
+    verbosity: u8,
+    memory: bool,
+    jobs: usize,
+    solutions: &[Solution],
+) -> RunSummary {
+    let mut timings: Vec<Timing> = Vec::with_capacity(puzzles_to_run.len());
+    let mut mismatches: Vec<(PuzzleId, Part)> = vec![];
+    let mut failures: Vec<(PuzzleId, RunStatus)> = vec![];
 
     let mut need_space = false;
 
-    // NOTE: use non-duplicate, sorted day values.
-    all_days()
-        .filter(|day| days_to_run.contains(day))
-        .for_each(|day| {
-            if need_space {
-                println!();
-            }
-            need_space = true;
+    // registered solutions were built with the profile and features of the current binary.
+    let is_release_build = !cfg!(debug_assertions);
+    let is_counting_build = !memory || counts_allocations();
+
+    // NOTE: use non-duplicate, sorted puzzle values.
+    let mut puzzles: Vec<PuzzleId> = puzzles_to_run.iter().copied().collect();
+    puzzles.sort_unstable();
+
+    if limits.memory.is_some() && !cfg!(target_os = "linux") {
+        eprintln!("Memory limits are only supported on Linux, ignoring the memory limit.");
+    }
+
+    // NOTE: the runner keeps the state of traces and visualizations per process,
+    // so days only run concurrently in their own processes.
+    let is_parallel = jobs > 1 && puzzles.len() > 1;
+    let runs_executables = limits.is_limited() || is_parallel;
+
+    let executables: HashMap<PuzzleId, PathBuf> = if runs_executables {
+        child_commands::build_solutions(&puzzles, is_release, memory).unwrap_or_else(|e| {
+            eprintln!("Failed to build the solutions: {e:?}");
+            process::exit(1);
+        })
+    } else {
+        HashMap::new()
+    };
+
+    let run_day = |puzzle: PuzzleId, output: Option<&OutputBuffer>| {
+        let solution = solutions
+            .iter()
+            .find(|solution| solution.puzzle == puzzle)
+            .filter(|_| (!is_release || is_release_build) && is_counting_build);
+
+        if runs_executables {
+            executables
+                .get(&puzzle)
+                .map(|executable| {
+                    child_commands::run_executable(
+                        executable,
+                        puzzle,
+                        bench_config.as_ref(),
+                        verbosity,
+                        memory,
+                        &limits,
+                        output,
+                    )
+                })
+                .transpose()
+        } else if let Some(solution) = solution {
+            run_in_process(solution, bench_config.as_ref(), verbosity, memory).map(Some)
+        } else {
+            child_commands::run_solution(
+                puzzle,
+                bench_config.as_ref(),
+                verbosity,
+                memory,
+                is_release,
+            )
+        }
+    };
+
+    let mut print_header = |puzzle: PuzzleId| {
+        if need_space {
+            println!();
+        }
+        need_space = true;
 
-            println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
-            println!("------");
+        println!("{ANSI_BOLD}{} Day {}{ANSI_RESET}", puzzle.year, puzzle.day);
+        println!("-----------");
+    };
+
+    let mut finish_day =
+        |puzzle: PuzzleId, run: Result<Option<(Vec<ReportEntry>, RunStatus)>, Error>| {
+            if verbosity > 0 {
+                print_trace(puzzle);
+            }
 
-            let output = child_commands::run_solution(day, is_timed, is_release).unwrap();
+            let (entries, status) = match run {
+                Ok(Some(run)) => run,
+                Ok(None) => {
+                    println!("Not solved.");
+                    return;
+                }
+                Err(e) => {
+                    println!("✖ Failed to run: {e}.");
+                    (vec![], RunStatus::Failed)
+                }
+            };
 
-            if output.is_empty() {
-                println!("Not solved.");
+            // a panicking step is caught by the runner, which then exits with a non-zero status.
+            let status = if matches!(status, RunStatus::Completed | RunStatus::Failed)
+                && entries.iter().any(|e| e.panic.is_some())
+            {
+                RunStatus::Panicked
             } else {
-                let val = child_commands::parse_exec_time(&output, day);
-                timings.push(val);
+                status
+            };
+
+            match status {
+                // the runner already printed the panic in place of the failed step,
+                // and a failing solution printed its error, e.g. a missing input.
+                RunStatus::Completed | RunStatus::Panicked | RunStatus::Failed => {}
+                RunStatus::TimedOut => println!(
+                    "✖ Timed out after {:.1?}.",
+                    limits.timeout.unwrap_or_default()
+                ),
+                RunStatus::Killed => println!("✖ Killed."),
             }
-        });
 
-    if is_timed {
+            if status != RunStatus::Completed {
+                failures.push((puzzle, status));
+            }
+
+            let mut timing = child_commands::collect_timing(&entries, puzzle);
+            timing.status = status;
+            timings.push(timing);
+
+            mismatches.extend(
+                child_commands::collect_mismatches(&entries)
+                    .into_iter()
+                    .map(|part| (puzzle, part)),
+            );
+        };
+
+    if is_parallel {
+        run_parallel(
+            &puzzles,
+            jobs,
+            |puzzle| {
+                let output = OutputBuffer::default();
+                let run = run_day(puzzle, Some(&output));
+                (output, run)
+            },
+            |puzzle, (output, run)| {
+                print_header(puzzle);
+                output.print();
+                finish_day(puzzle, run);
+            },
+        );
+    } else {
+        for puzzle in puzzles {
+            print_header(puzzle);
+            let run = run_day(puzzle, None);
+            finish_day(puzzle, run);
+        }
+    }
+
+    let timings = bench_config.map(|_| {
         let timings = Timings { data: timings };
-        let total_millis = timings.total_millis();
+        let total_millis = timings.total_millis(BenchFigure::Mean);
         println!(
             "\n{ANSI_BOLD}Total (Run):{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}"
         );
-        Some(timings)
-    } else {
-        None
+        timings
+    });
+
+    RunSummary {
+        timings,
+        mismatches,
+        failures,
+    }
+}
+
+/// Runs `run` for every puzzle on up to `jobs` threads, and calls `finish` with the results in the order of `puzzles`.
+/// A result is finished as soon as the results of all preceding puzzles are.
+fn run_parallel<T: Send>(
+    puzzles: &[PuzzleId],
+    jobs: usize,
+    run: impl Fn(PuzzleId) -> T + Sync,
+    mut finish: impl FnMut(PuzzleId, T),
+) {
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.min(puzzles.len()) {
+            let (next, run, sender) = (&next, &run, sender.clone());
+            scope.spawn(move || {
+                loop {
+                    let index = next.fetch_add(1, Ordering::Relaxed);
+                    let Some(&puzzle) = puzzles.get(index) else {
+                        break;
+                    };
+                    if sender.send((index, run(puzzle))).is_err() {
+                        break;
+                    }
+                }
+            });
+        }
+        drop(sender);
+
+        let mut pending: HashMap<usize, T> = HashMap::new();
+        let mut next_to_finish = 0;
+        for (index, result) in receiver {
+            pending.insert(index, result);
+            while let Some(result) = pending.remove(&next_to_finish) {
+                finish(puzzles[next_to_finish], result);
+                next_to_finish += 1;
+            }
+        }
+    });
+}
+
+/// The output of a solution process, collected while other days are running.
+#[derive(Default)]
+pub struct OutputBuffer {
+    lines: Mutex<Vec<OutputLine>>,
+}
+
+enum OutputLine {
+    Stdout(String),
+    Stderr(String),
+}
+
+impl OutputBuffer {
+    fn push(&self, line: OutputLine) {
+        self.lines
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(line);
+    }
+
+    /// Prints the collected lines to stdout and stderr, in the order they were written.
+    fn print(self) {
+        let lines = self
+            .lines
+            .into_inner()
+            .unwrap_or_else(PoisonError::into_inner);
+        for line in lines {
+            match line {
+                OutputLine::Stdout(line) => println!("{line}"),
+                OutputLine::Stderr(line) => eprintln!("{line}"),
+            }
+        }
     }
 }
 
-#[allow(dead_code)]
 #[derive(Debug)]
 pub enum Error {
     BrokenPipe,
     IO(io::Error),
+    Report(String),
+    Build(String),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::BrokenPipe => write!(f, "the output of the solution could not be read"),
+            Error::IO(e) => write!(f, "{e}"),
+            Error::Report(e) => write!(f, "the report could not be read, {e}"),
+            Error::Build(status) => write!(f, "the build failed with {status}"),
+        }
+    }
 }
 
 impl From<std::io::Error> for Error {
@@ -59,134 +318,357 @@ impl From<std::io::Error> for Error {
     }
 }
 
+/// Runs a registered solution in the current process and returns its report.
+/// A solution that returns an error failed, like a child process exiting with a non-zero status.
+fn run_in_process(
+    solution: &Solution,
+    bench_config: Option<&BenchConfig>,
+    verbosity: u8,
+    memory: bool,
+) -> Result<(Vec<ReportEntry>, RunStatus), Error> {
+    let report_path = report_path(solution.puzzle);
+    let _ = fs::remove_file(&report_path);
+
+    let mut args = SolutionArgs::default();
+    args.report = Some(report_path.clone());
+    args.memory = memory;
+    if verbosity > 0 {
+        args.verbosity = verbosity;
+        args.trace = Some(trace_path(solution.puzzle));
+    }
+    if let Some(bench_config) = bench_config {
+        args.bench = true;
+        args.bench_config = *bench_config;
+    }
+
+    // a missing input does not stop the other solutions, like a failing child process.
+    let status = match (solution.run)(&args) {
+        Ok(()) => RunStatus::Completed,
+        Err(e) => {
+            eprintln!("{e}");
+            RunStatus::Failed
+        }
+    };
+
+    let report = read_report(&report_path).map_err(Error::Report);
+    let _ = fs::remove_file(&report_path);
+    report.map(|entries| (entries, status))
+}
+
+/// A temporary file for the report of a solution run.
+fn report_path(puzzle: PuzzleId) -> PathBuf {
+    env::temp_dir().join(format!(
+        "aoc-report-{}-{}.jsonl",
+        process::id(),
+        puzzle.bin_name()
+    ))
+}
+
+/// A temporary file for the debug messages of a solution run.
+fn trace_path(puzzle: PuzzleId) -> PathBuf {
+    env::temp_dir().join(format!(
+        "aoc-trace-{}-{}.log",
+        process::id(),
+        puzzle.bin_name()
+    ))
+}
+
+/// Prints the debug messages of a solution run, separately from its results.
+fn print_trace(puzzle: PuzzleId) {
+    let path = trace_path(puzzle);
+    let trace = fs::read_to_string(&path).unwrap_or_default();
+    let _ = fs::remove_file(&path);
+
+    if trace.is_empty() {
+        return;
+    }
+
+    eprintln!("{ANSI_ITALIC}Debug:{ANSI_RESET}");
+    for line in trace.lines() {
+        eprintln!("  {line}");
+    }
+}
+
 #[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+pub fn get_path_for_bin(puzzle: PuzzleId) -> String {
+    format!("./src/bin/{}.rs", puzzle.bin_name())
 }
 
 /// All solutions live in isolated binaries.
-/// This module encapsulates interaction with these binaries, both invoking them as well as parsing the timing output.
+/// This module encapsulates interaction with these binaries, both invoking them as well as reading their reports.
 pub mod child_commands {
-    use super::{Error, get_path_for_bin};
-    use crate::template::Day;
+    use super::{Error, OutputBuffer, OutputLine, get_path_for_bin, report_path, trace_path};
+    use crate::template::report::{ReportEntry, ReportStep, read_report};
+    use crate::template::{BenchConfig, MemoryUsage, Part, PuzzleId, RunLimits, RunStatus};
     use std::{
+        collections::HashMap,
+        fs,
         io::{BufRead, BufReader},
-        path::Path,
-        process::{Command, Stdio},
+        path::{Path, PathBuf},
+        process::{Command, ExitStatus, Stdio},
+        str::FromStr,
         thread,
+        time::{Duration, Instant},
     };
-
-    /// Run the solution bin for a given day
-    pub fn run_solution(day: Day, is_timed: bool, is_release: bool) -> Result<Vec<String>, Error> {
+    use tinyjson::JsonValue;
+
+    /// The feature that compiles in the counting allocator, needed to run solutions with `--memory`.
+    const COUNTING_FEATURE: &str = "count-allocations";
+
+    /// How often a limited child is checked for having exited.
+    const POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+    /// Run the solution bin for a given day and return its report.
+    /// Returns `None` if the day has not been scaffolded yet.
+    pub fn run_solution(
+        puzzle: PuzzleId,
+        bench_config: Option<&BenchConfig>,
+        verbosity: u8,
+        memory: bool,
+        is_release: bool,
+    ) -> Result<Option<(Vec<ReportEntry>, RunStatus)>, Error> {
         // skip command invocation for days that have not been scaffolded yet.
-        if !Path::new(&get_path_for_bin(day)).exists() {
-            return Ok(vec![]);
+        if !Path::new(&get_path_for_bin(puzzle)).exists() {
+            return Ok(None);
         }
 
-        let day_padded = day.to_string();
-        let mut args = vec!["run", "--quiet", "--bin", &day_padded];
+        let mut cmd = Command::new("cargo");
+        cmd.args(["run", "--quiet", "--bin", &puzzle.bin_name()]);
 
         if is_release {
-            args.push("--release");
+            cmd.arg("--release");
         }
 
-        if is_timed {
-            // mirror `--time` flag to child invocations.
-            args.push("--");
-            args.push("--time");
+        if memory {
+            cmd.args(["--features", COUNTING_FEATURE]);
         }
 
-        // spawn child command with piped stdout/stderr.
-        // forward output to stdout/stderr while grabbing stdout lines.
-
-        let mut cmd = Command::new("cargo")
-            .args(&args)
-            .stdout(Stdio::piped())
-            .stderr(Stdio::piped())
-            .spawn()?;
+        cmd.arg("--");
+        run_child(
+            cmd,
+            puzzle,
+            bench_config,
+            verbosity,
+            memory,
+            &RunLimits::default(),
+            None,
+        )
+        .map(Some)
+    }
 
-        let stdout = BufReader::new(cmd.stdout.take().ok_or(super::Error::BrokenPipe)?);
-        let stderr = BufReader::new(cmd.stderr.take().ok_or(super::Error::BrokenPipe)?);
+    /// Builds the solution bins of the given days and returns the paths of their executables.
+    /// Days that have not been scaffolded yet are skipped.
+    pub fn build_solutions(
+        puzzles: &[PuzzleId],
+        is_release: bool,
+        memory: bool,
+    ) -> Result<HashMap<PuzzleId, PathBuf>, Error> {
+        let puzzles: HashMap<String, PuzzleId> = puzzles
+            .iter()
+            .filter(|puzzle| Path::new(&get_path_for_bin(**puzzle)).exists())
+            .map(|puzzle| (puzzle.bin_name(), *puzzle))
+            .collect();
 
-        let mut output = vec![];
+        if puzzles.is_empty() {
+            return Ok(HashMap::new());
+        }
 
-        let thread = thread::spawn(move || {
-            stderr.lines().for_each(|line| {
-                eprintln!("{}", line.unwrap());
-            });
-        });
+        let mut cmd = Command::new("cargo");
+        cmd.args([
+            "build",
+            "--quiet",
+            "--message-format=json-render-diagnostics",
+        ]);
+        for bin_name in puzzles.keys() {
+            cmd.args(["--bin", bin_name]);
+        }
+        if is_release {
+            cmd.arg("--release");
+        }
+        if memory {
+            cmd.args(["--features", COUNTING_FEATURE]);
+        }
 
-        for line in stdout.lines() {
-            let line = line.unwrap();
-            println!("{line}");
-            output.push(line);
+        let output = cmd.stderr(Stdio::inherit()).output()?;
+        if !output.status.success() {
+            return Err(Error::Build(output.status.to_string()));
         }
 
-        thread.join().unwrap();
-        cmd.wait()?;
+        // cargo reports the path of every built executable as a `compiler-artifact` message.
+        let executables = String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .filter_map(|line| {
+                let json = JsonValue::from_str(line).ok()?;
+                let message = json.get::<HashMap<String, JsonValue>>()?;
+                let name = message
+                    .get("target")?
+                    .get::<HashMap<String, JsonValue>>()?
+                    .get("name")?
+                    .get::<String>()?;
+                let executable = message.get("executable")?.get::<String>()?;
+                Some((*puzzles.get(name)?, PathBuf::from(executable)))
+            })
+            .collect();
 
-        Ok(output)
+        Ok(executables)
     }
 
-    pub fn parse_exec_time(output: &[String], day: Day) -> super::Timing {
-        let mut timings = super::Timing {
-            day,
-            part_1: None,
-            part_2: None,
-            total_nanos: 0_f64,
+    /// Run a built solution executable within the given limits and return its report.
+    /// With an `output` buffer, the output of the solution is collected instead of printed.
+    pub fn run_executable(
+        executable: &Path,
+        puzzle: PuzzleId,
+        bench_config: Option<&BenchConfig>,
+        verbosity: u8,
+        memory: bool,
+        limits: &RunLimits,
+        output: Option<&OutputBuffer>,
+    ) -> Result<(Vec<ReportEntry>, RunStatus), Error> {
+        let cmd = match limits.memory.filter(|_| cfg!(target_os = "linux")) {
+            Some(bytes) => {
+                // `ulimit -v` limits the address space (`RLIMIT_AS`) in KiB, then runs the solution in place of the shell.
+                let mut cmd = Command::new("sh");
+                cmd.arg("-c")
+                    .arg(format!("ulimit -v {} && exec \"$0\" \"$@\"", bytes / 1024))
+                    .arg(executable);
+                cmd
+            }
+            None => Command::new(executable),
         };
 
-        output
-            .iter()
-            .filter_map(|l| {
-                if !l.contains(" samples)") {
-                    return None;
-                }
+        run_child(cmd, puzzle, bench_config, verbosity, memory, limits, output)
+    }
 
-                let Some((timing_str, nanos)) = parse_time(l) else {
-                    eprintln!("Could not parse timings from line: {l}");
-                    return None;
-                };
+    /// Spawn a solution process with piped stdout/stderr, forward (or buffer) its output and collect its report.
+    /// The process is killed if it exceeds the timeout.
+    fn run_child(
+        mut cmd: Command,
+        puzzle: PuzzleId,
+        bench_config: Option<&BenchConfig>,
+        verbosity: u8,
+        memory: bool,
+        limits: &RunLimits,
+        output: Option<&OutputBuffer>,
+    ) -> Result<(Vec<ReportEntry>, RunStatus), Error> {
+        let report_path = report_path(puzzle);
+        let _ = fs::remove_file(&report_path);
+
+        cmd.arg("--report").arg(&report_path);
+
+        if let Some(bench_config) = bench_config {
+            // bench child invocations when timing.
+            cmd.arg("--bench");
+            cmd.args(bench_config.to_args());
+        }
 
-                let part = l.split(':').next()?;
-                Some((part, timing_str, nanos))
-            })
-            .for_each(|(part, timing_str, nanos)| {
-                if part.contains("Part 1") {
-                    timings.part_1 = Some(timing_str.into());
-                } else if part.contains("Part 2") {
-                    timings.part_2 = Some(timing_str.into());
-                }
+        if memory {
+            cmd.arg("--memory");
+        }
 
-                timings.total_nanos += nanos;
+        if verbosity > 0 {
+            // debug messages are collected in a file, to be printed after the results.
+            cmd.args(std::iter::repeat_n("--verbose", verbosity.into()));
+            cmd.arg("--trace").arg(trace_path(puzzle));
+        }
+
+        let mut child = cmd.stdout(Stdio::piped()).stderr(Stdio::piped()).spawn()?;
+
+        let stdout = BufReader::new(child.stdout.take().ok_or(super::Error::BrokenPipe)?);
+        let stderr = BufReader::new(child.stderr.take().ok_or(super::Error::BrokenPipe)?);
+
+        let deadline = limits.timeout.map(|timeout| Instant::now() + timeout);
+        let status = thread::scope(|scope| {
+            scope.spawn(|| {
+                stdout.lines().for_each(|line| {
+                    let line = line.unwrap();
+                    match output {
+                        Some(output) => output.push(OutputLine::Stdout(line)),
+                        None => println!("{line}"),
+                    }
+                });
+            });
+            scope.spawn(|| {
+                stderr.lines().for_each(|line| {
+                    let line = line.unwrap();
+                    match output {
+                        Some(output) => output.push(OutputLine::Stderr(line)),
+                        None => eprintln!("{line}"),
+                    }
+                });
             });
 
-        timings
+            loop {
+                if let Some(status) = child.try_wait()? {
+                    break Ok::<_, Error>(run_status(status));
+                }
+                if deadline.is_some_and(|deadline| Instant::now() >= deadline) {
+                    child.kill()?;
+                    child.wait()?;
+                    break Ok(RunStatus::TimedOut);
+                }
+                thread::sleep(POLL_INTERVAL);
+            }
+        })?;
+
+        let report = read_report(&report_path).map_err(Error::Report);
+        let _ = fs::remove_file(&report_path);
+        report.map(|entries| (entries, status))
     }
 
-    fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
-        s.split(postfix).next()?.parse().ok()
+    /// NOTE: a process without an exit code was terminated by a signal,
+    /// e.g. after failing to allocate memory within the memory limit.
+    /// Any other non-zero exit means the solution failed, e.g. because its input is missing.
+    fn run_status(status: ExitStatus) -> RunStatus {
+        if status.success() {
+            RunStatus::Completed
+        } else if status.code().is_none() {
+            RunStatus::Killed
+        } else {
+            RunStatus::Failed
+        }
     }
 
-    fn parse_time(line: &str) -> Option<(&str, f64)> {
-        // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-        let str_timing = line
-            .split(" samples)")
-            .next()?
-            .split('(')
-            .next_back()?
-            .split('@')
-            .next()?
-            .trim();
-
-        let parsed_timing = match str_timing {
-            s if s.contains("ns") => s.split("ns").next()?.parse::<f64>().ok(),
-            s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
-            s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
-            s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
-        }?;
-
-        Some((str_timing, parsed_timing))
+    /// Collects the benchmark statistics of each step of a report.
+    pub fn collect_timing(entries: &[ReportEntry], puzzle: PuzzleId) -> super::Timing {
+        let mut timing = super::Timing {
+            puzzle,
+            parse: None,
+            part_1: None,
+            part_2: None,
+            memory: MemoryUsage::default(),
+            status: RunStatus::Completed,
+        };
+
+        for entry in entries {
+            match entry.step {
+                ReportStep::Parse => {
+                    timing.parse = entry.stats;
+                    timing.memory.parse = entry.memory;
+                }
+                ReportStep::Part(Part::One) => {
+                    timing.part_1 = entry.stats;
+                    timing.memory.part_1 = entry.memory;
+                }
+                ReportStep::Part(Part::Two) => {
+                    timing.part_2 = entry.stats;
+                    timing.memory.part_2 = entry.memory;
+                }
+            }
+        }
+
+        timing
+    }
+
+    /// Returns the parts whose answer does not match their known answer.
+    pub fn collect_mismatches(entries: &[ReportEntry]) -> Vec<Part> {
+        entries
+            .iter()
+            .filter(|entry| entry.matches_known == Some(false))
+            .filter_map(|entry| match entry.step {
+                ReportStep::Part(part) => Some(part),
+                ReportStep::Parse => None,
+            })
+            .collect()
     }
 
     /// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
@@ -205,53 +687,111 @@ pub mod child_commands {
 
     #[cfg(feature = "test_lib")]
     mod tests {
-        use super::parse_exec_time;
+        use std::{thread, time::Duration};
 
-        use crate::day;
+        use super::super::run_parallel;
+        use super::{collect_mismatches, collect_timing};
+
+        use crate::{
+            puzzle,
+            template::report::ReportStep,
+            template::test_fixtures::mock_entry,
+            template::{BenchFigure, MemoryStats, Part},
+        };
 
         #[test]
-        fn parses_execution_times() {
-            let res = parse_exec_time(
+        fn collects_execution_times() {
+            let res = collect_timing(
                 &[
-                    "Part 1: 0 (74.13ns @ 100000 samples)".into(),
-                    "Part 2: 10 (74.13ms @ 99999 samples)".into(),
-                    "".into(),
+                    mock_entry(ReportStep::Part(Part::One), Some(74.1)),
+                    mock_entry(ReportStep::Part(Part::Two), Some(74_100_000.0)),
                 ],
-                day!(1),
+                puzzle!(2025, 1),
             );
-            assert_approx_eq!(res.total_nanos, 74130074.13_f64);
-            assert_eq!(res.part_1.unwrap(), "74.13ns");
-            assert_eq!(res.part_2.unwrap(), "74.13ms");
+            assert_approx_eq!(res.total_nanos(BenchFigure::Mean), 74100074.1_f64);
+            assert_approx_eq!(res.total_nanos(BenchFigure::Median), 74100074.1_f64);
+            let part_1 = res.part_1.unwrap();
+            assert_eq!(part_1.samples, 10);
+            assert_eq!(part_1.outliers, 1);
+            assert_approx_eq!(res.part_2.unwrap().p95, 148_200_000_f64);
+            assert_eq!(res.parse.is_none(), true);
         }
 
         #[test]
-        fn parses_with_patterns_in_input() {
-            let res = parse_exec_time(
+        fn collects_missing_parts() {
+            let res = collect_timing(
                 &[
-                    "Part 1: @ @ @ ( ) ms (2s @ 5 samples)".into(),
-                    "Part 2: 10s (100ms @ 1 samples)".into(),
-                    "".into(),
+                    mock_entry(ReportStep::Part(Part::One), None),
+                    mock_entry(ReportStep::Part(Part::Two), None),
                 ],
-                day!(1),
+                puzzle!(2025, 1),
             );
-            assert_approx_eq!(res.total_nanos, 2100000000_f64);
-            assert_eq!(res.part_1.unwrap(), "2s");
-            assert_eq!(res.part_2.unwrap(), "100ms");
+            assert_approx_eq!(res.total_nanos(BenchFigure::Mean), 0_f64);
+            assert_eq!(res.part_1.is_none(), true);
+            assert_eq!(res.part_2.is_none(), true);
         }
 
         #[test]
-        fn parses_missing_parts() {
-            let res = parse_exec_time(
+        fn collects_parse_times() {
+            let res = collect_timing(
                 &[
-                    "Part 1: ✖        ".into(),
-                    "Part 2: ✖        ".into(),
-                    "".into(),
+                    mock_entry(ReportStep::Parse, Some(1_000_000.0)),
+                    mock_entry(ReportStep::Part(Part::One), Some(2_000_000.0)),
                 ],
-                day!(1),
+                puzzle!(2025, 1),
             );
-            assert_approx_eq!(res.total_nanos, 0_f64);
-            assert_eq!(res.part_1.is_none(), true);
-            assert_eq!(res.part_2.is_none(), true);
+            assert_approx_eq!(res.parse.unwrap().mean, 1_000_000_f64);
+            assert_approx_eq!(res.total_nanos(BenchFigure::Mean), 3_000_000_f64);
+        }
+
+        #[test]
+        fn collects_memory_usage() {
+            let mut part_2 = mock_entry(ReportStep::Part(Part::Two), Some(1.0));
+            part_2.memory = Some(MemoryStats {
+                peak_bytes: 2_048,
+                allocations: 4,
+            });
+
+            let res = collect_timing(
+                &[mock_entry(ReportStep::Part(Part::One), Some(1.0)), part_2],
+                puzzle!(2025, 1),
+            );
+            assert_eq!(res.memory.part_1, None);
+            assert_eq!(res.memory.part_2.unwrap().peak_bytes, 2_048);
+        }
+
+        #[test]
+        fn finishes_parallel_runs_in_order() {
+            let puzzles = [puzzle!(2025, 1), puzzle!(2025, 2), puzzle!(2025, 3)];
+            let mut finished = vec![];
+
+            // the first day takes longest, so it finishes last without reordering.
+            run_parallel(
+                &puzzles,
+                3,
+                |puzzle| {
+                    let delay = 4 - u64::from(puzzle.day.into_inner());
+                    thread::sleep(Duration::from_millis(delay * 20));
+                    puzzle.day.into_inner()
+                },
+                |puzzle, day| finished.push((puzzle, day)),
+            );
+
+            assert_eq!(
+                finished,
+                vec![(puzzles[0], 1), (puzzles[1], 2), (puzzles[2], 3)]
+            );
+        }
+
+        #[test]
+        fn collects_answer_mismatches() {
+            let mut part_1 = mock_entry(ReportStep::Part(Part::One), None);
+            part_1.matches_known = Some(true);
+            let mut part_2 = mock_entry(ReportStep::Part(Part::Two), None);
+            part_2.matches_known = Some(false);
+
+            let res = collect_mismatches(&[mock_entry(ReportStep::Parse, None), part_1, part_2]);
+            assert_eq!(res, vec![Part::Two]);
         }
     }
 }
@@ -1,160 +1,624 @@
 /// Encapsulates code that interacts with solution functions.
+use std::cell::{Cell, RefCell};
 use std::fmt::Display;
 use std::hint::black_box;
 use std::io::{Write, stdout};
-use std::process::Output;
+use std::panic::{self, AssertUnwindSafe};
+use std::str::FromStr;
+use std::sync::Once;
 use std::time::{Duration, Instant};
-use std::{cmp, env, process};
+use std::{cmp, process};
 
-use crate::template::ANSI_BOLD;
-use crate::template::{ANSI_ITALIC, ANSI_RESET, Day, aoc_cli};
+use crate::template::answers::{AnswerCheck, Answers};
+use crate::template::aoc_cli::SubmitOutcome;
+use crate::template::aoc_client::{self, AocBackend};
+use crate::template::bench_stats::{BenchStats, format_nanos};
+use crate::template::memory::MemoryStats;
+use crate::template::report::{ReportEntry, ReportStep};
+use crate::template::solution_args::{InputSource, SolutionArgs};
+use crate::template::submissions::{Submissions, Verdict};
+use crate::template::trace::{TraceScope, without_traces};
+use crate::template::visualizer::{VisualizeScope, without_frames};
+use crate::template::{ANSI_BOLD, Answer, BenchConfig};
+use crate::template::{ANSI_ITALIC, ANSI_RESET, Part, PuzzleId, aoc_cli};
 
-pub fn run_part<I: Copy, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
+const WARM_UP_TIME: Duration = Duration::from_millis(100);
+
+/// A solution as registered by the `solution!` macro.
+/// The main binary compiles in all solutions of `src/bin`, so it can run them without spawning `cargo`.
+#[derive(Clone, Copy)]
+pub struct Solution {
+    pub puzzle: PuzzleId,
+    /// Runs the parts of the solution. Fails if an input can not be read or a step panicked.
+    pub run: fn(&SolutionArgs) -> Result<(), String>,
+}
+
+/// Converts the raw input of a solution into the argument of a part or `parse` function.
+pub trait SolutionInput<'a> {
+    fn from_input(input: &'a str) -> Self;
+}
+
+impl<'a> SolutionInput<'a> for &'a str {
+    fn from_input(input: &'a str) -> Self {
+        input
+    }
+}
+
+impl<'a> SolutionInput<'a> for &'a [u8] {
+    fn from_input(input: &'a str) -> Self {
+        input.as_bytes()
+    }
+}
+
+impl SolutionInput<'_> for String {
+    fn from_input(input: &str) -> Self {
+        input.to_owned()
+    }
+}
+
+/// Converts the output of a shared `parse` function into the argument of a part.
+/// Parts either borrow the parsed value, or take ownership of a clone of it.
+/// A parsed `Vec` can also be borrowed as a slice.
+pub trait FromParsed<'p, P> {
+    fn from_parsed(parsed: &'p P) -> Self;
+}
+
+impl<'p, P> FromParsed<'p, P> for &'p P {
+    fn from_parsed(parsed: &'p P) -> Self {
+        parsed
+    }
+}
+
+impl<'p, T> FromParsed<'p, Vec<T>> for &'p [T] {
+    fn from_parsed(parsed: &'p Vec<T>) -> Self {
+        parsed
+    }
+}
+
+impl<P: Clone> FromParsed<'_, P> for P {
+    fn from_parsed(parsed: &P) -> Self {
+        parsed.clone()
+    }
+}
+
+/// Tells a `parse` function that returns a `Result` apart from one that returns its value directly.
+/// The `solution!` macro calls `(&parsed).parse_kind().into_parsed(parsed)`: for a `Result`, the
+/// method of [`ResultParseKind`] is found first, as it takes one reference less than the one of
+/// [`ValueParseKind`].
+pub struct ResultParse;
+
+/// See [`ResultParse`].
+pub struct ValueParse;
+
+pub trait ResultParseKind {
+    fn parse_kind(&self) -> ResultParse {
+        ResultParse
+    }
+}
+
+impl<T, E: Display> ResultParseKind for Result<T, E> {}
+
+pub trait ValueParseKind {
+    fn parse_kind(&self) -> ValueParse {
+        ValueParse
+    }
+}
+
+impl<P> ValueParseKind for &P {}
+
+impl ResultParse {
+    pub fn into_parsed<T, E: Display>(self, parsed: Result<T, E>) -> Result<T, String> {
+        parsed.map_err(|e| e.to_string())
+    }
+}
+
+impl ValueParse {
+    pub fn into_parsed<P>(self, parsed: P) -> Result<P, String> {
+        Ok(parsed)
+    }
+}
+
+/// Converts the return value of a part into its answer.
+/// Parts return `Option<T>`, or `Result<T, E>` to explain why they failed.
+pub trait PartOutput {
+    /// The answer of the part, `Ok(None)` if it is not solved or `Err` with the reason it failed.
+    fn to_answer(&self) -> Result<Option<Answer>, String>;
+}
+
+impl<T: Display> PartOutput for Option<T> {
+    fn to_answer(&self) -> Result<Option<Answer>, String> {
+        Ok(self.as_ref().map(read_answer))
+    }
+}
+
+impl<T: Display, E: Display> PartOutput for Result<T, E> {
+    fn to_answer(&self) -> Result<Option<Answer>, String> {
+        match self {
+            Ok(result) => Ok(Some(read_answer(result))),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+}
+
+/// Reads the output of a part back as an [`Answer`].
+fn read_answer(result: &impl Display) -> Answer {
+    Answer::from_str(&result.to_string()).unwrap_or_else(|e| match e {})
+}
+
+/// Runs a solution part. `input` provides the argument of every run of the part,
+/// it is called outside of the timed region, e.g. to clone the input of a mutating algorithm.
+/// A panic of the part is reported as a failed part, so that the other part still runs.
+pub fn run_part<I, O: PartOutput>(
+    func: impl Fn(I) -> O,
+    input: impl Fn() -> I,
+    puzzle: PuzzleId,
+    part: Part,
+    args: &SolutionArgs,
+) -> Result<(), String> {
     let part_str = format!("Part {part}");
 
-    let (result, duration, samples) =
-        run_timed(func, input, |result| print_result(result, &part_str, ""));
+    let bench_config = args.bench.then_some(&args.bench_config);
+    let traces = TraceScope::start(args.verbosity, args.trace.as_deref());
+    let visualization = VisualizeScope::start(args.visualize, puzzle, ReportStep::Part(part));
+    let run = catch_panic(|| {
+        run_timed(func, input, args.memory, bench_config, |result| {
+            let output = result.to_answer();
+            print_result(output.as_ref(), &part_str, "", "");
+        })
+    });
+    drop(traces);
+
+    let (result, duration, memory, stats) = match run {
+        Ok(run) => run,
+        Err(panic) => {
+            print!("\r");
+            println!("{part_str}: ✖ {panic}");
+            // the frames up to a panic are shown as well, they might tell what went wrong.
+            visualization.finish();
+            write_report(args, ReportEntry::panicked(ReportStep::Part(part), &panic));
+            return Err(format!("part {part} panicked."));
+        }
+    };
+
+    let output = result.to_answer();
+    let answer = output.as_ref().ok().and_then(Option::as_ref);
+    // unreadable letter grids are compared and recorded as they are rendered.
+    let result = answer.map(|answer| answer.submission().unwrap_or_else(|| answer.to_string()));
+
+    // only the puzzle input has a known answer to compare against.
+    let check = if args.input == InputSource::Puzzle {
+        match Answers::read_from_file() {
+            Ok(answers) => answers.check(puzzle, part, result.as_deref()),
+            Err(e) => {
+                eprintln!("failed to check the answer of part {part}: {e}");
+                AnswerCheck::Unknown
+            }
+        }
+    } else {
+        AnswerCheck::Unknown
+    };
+
+    print_result(
+        output.as_ref(),
+        &part_str,
+        &format_duration(&duration, stats.as_ref()),
+        &format_check(&check),
+    );
+
+    if let Some(stats) = stats {
+        println!("  └ {stats}");
+    }
+
+    if let Some(memory) = memory {
+        println!("  └ {memory}");
+    }
+
+    visualization.finish();
+
+    write_report(
+        args,
+        ReportEntry {
+            step: ReportStep::Part(part),
+            answer: result.clone(),
+            matches_known: match check {
+                AnswerCheck::Unknown => None,
+                AnswerCheck::Correct => Some(true),
+                AnswerCheck::Incorrect { .. } => Some(false),
+            },
+            nanos: duration.as_nanos() as f64,
+            stats,
+            memory,
+            error: output.as_ref().err().cloned(),
+            panic: None,
+        },
+    );
 
-    print_result(&result, &part_str, &format_duration(&duration, samples));
+    let (Some(answer), Some(result)) = (answer, result) else {
+        return Ok(());
+    };
+
+    if args.accept == Some(part) {
+        record_answer(puzzle, part, &result);
+    }
 
-    if let Some(result) = result {
-        submit_result(result, day, part);
+    if submit_result(answer, puzzle, part, args) == Some(SubmitOutcome::Correct) {
+        record_answer(puzzle, part, &result);
     }
+
+    Ok(())
+}
+
+/// Run the shared `parse` function of a solution, then the parts on its output.
+/// Parts that read the same input share the parsed value, which may borrow from the input.
+/// Parts can't run on an input that failed to parse, they are skipped.
+/// `parse` returns an `Err` with the reason if the input can't be parsed, see [`ResultParse`].
+pub fn run_parsed<'a, I: SolutionInput<'a>, P>(
+    parse: impl Fn(I) -> Result<P, String>,
+    inputs: &'a [(Part, String)],
+    puzzle: PuzzleId,
+    args: &SolutionArgs,
+    mut run: impl FnMut(Part, &P) -> Result<(), String>,
+) -> Result<(), String> {
+    let mut parsed: Option<(&str, Result<P, String>)> = None;
+    let mut result = Ok(());
+
+    for (part, input) in inputs {
+        if parsed
+            .as_ref()
+            .is_none_or(|(parsed_input, _)| parsed_input != input)
+        {
+            parsed = Some((input, run_parse(&parse, input, puzzle, args)));
+        }
+
+        if let Some((_, value)) = &parsed {
+            result = result.and(
+                value
+                    .as_ref()
+                    .map_err(Clone::clone)
+                    .and_then(|value| run(*part, value)),
+            );
+        }
+    }
+
+    result
 }
 
-/// Run a solution part. The behavior differs depending on whether we are running a release or debug build:
-///  1. in debug, the function is executed once.
-///  2. in release, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
-fn run_timed<I: Copy, T>(
+fn run_parse<'a, I: SolutionInput<'a>, P>(
+    parse: impl Fn(I) -> Result<P, String>,
+    input: &'a str,
+    puzzle: PuzzleId,
+    args: &SolutionArgs,
+) -> Result<P, String> {
+    // debug messages would be printed on the same line.
+    if args.verbosity == 0 {
+        print!("Parse:");
+        let _ = stdout().flush();
+    }
+
+    let bench_config = args.bench.then_some(&args.bench_config);
+    let traces = TraceScope::start(args.verbosity, args.trace.as_deref());
+    let visualization = VisualizeScope::start(args.visualize, puzzle, ReportStep::Parse);
+    let run = catch_panic(|| {
+        run_timed(
+            parse,
+            || I::from_input(input),
+            args.memory,
+            bench_config,
+            |_| {},
+        )
+    });
+    drop(traces);
+
+    let (parsed, duration, memory, stats) = match run {
+        Ok(run) => run,
+        Err(panic) => {
+            print!("\r");
+            println!("Parse: ✖ {panic}");
+            visualization.finish();
+            write_report(args, ReportEntry::panicked(ReportStep::Parse, &panic));
+            return Err("parse panicked.".into());
+        }
+    };
+
+    let duration_str = format_duration(&duration, stats.as_ref());
+    match &parsed {
+        Ok(_) => {
+            print!("\r");
+            println!("Parse:{duration_str}");
+        }
+        Err(e) => print_result(Err(e), "Parse", &duration_str, ""),
+    }
+
+    if let Some(stats) = stats {
+        println!("  └ {stats}");
+    }
+
+    if let Some(memory) = memory {
+        println!("  └ {memory}");
+    }
+
+    visualization.finish();
+
+    write_report(
+        args,
+        ReportEntry {
+            step: ReportStep::Parse,
+            answer: None,
+            matches_known: None,
+            nanos: duration.as_nanos() as f64,
+            stats,
+            memory,
+            error: parsed.as_ref().err().cloned(),
+            panic: None,
+        },
+    );
+
+    parsed.map_err(|_| "parse failed.".into())
+}
+
+/// A panic caught while running a step of a solution.
+struct CaughtPanic {
+    message: String,
+    location: Option<String>,
+}
+
+impl Display for CaughtPanic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.location {
+            Some(location) => write!(f, "panicked at {location}: {}", self.message),
+            None => write!(f, "panicked: {}", self.message),
+        }
+    }
+}
+
+thread_local! {
+    static CATCHING_PANICS: Cell<bool> = const { Cell::new(false) };
+    static CAUGHT_PANIC: RefCell<Option<CaughtPanic>> = const { RefCell::new(None) };
+}
+
+/// Runs `func`, catching a panic instead of unwinding further.
+/// The panic hook records the message and location of a caught panic in place of printing it,
+/// panics outside of `catch_panic` are still handled by the previous hook.
+fn catch_panic<T>(func: impl FnOnce() -> T) -> Result<T, CaughtPanic> {
+    static INSTALL_HOOK: Once = Once::new();
+    INSTALL_HOOK.call_once(|| {
+        let previous_hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if CATCHING_PANICS.get() {
+                CAUGHT_PANIC.set(Some(CaughtPanic {
+                    message: info.payload_as_str().unwrap_or("Box<dyn Any>").into(),
+                    location: info.location().map(ToString::to_string),
+                }));
+            } else {
+                previous_hook(info);
+            }
+        }));
+    });
+
+    CATCHING_PANICS.set(true);
+    let result = panic::catch_unwind(AssertUnwindSafe(func));
+    CATCHING_PANICS.set(false);
+
+    result.map_err(|_| {
+        CAUGHT_PANIC.take().unwrap_or_else(|| CaughtPanic {
+            message: "unknown panic".into(),
+            location: None,
+        })
+    })
+}
+
+/// Run a solution part. The behavior differs depending on whether benching is requested:
+///  1. by default, the function is executed once.
+///  2. with `--bench`, the function is warmed up and then benched (approx. `--bench-time` of execution time or `--min-samples`, whatever take longer.)
+///
+/// With `memory`, the heap usage of the first run is measured, which adds the overhead of counting to its duration.
+fn run_timed<I, T>(
     func: impl Fn(I) -> T,
-    input: I,
+    input: impl Fn() -> I,
+    memory: bool,
+    bench_config: Option<&BenchConfig>,
     hook: impl Fn(&T),
-) -> (T, Duration, u128) {
+) -> (T, Duration, Option<MemoryStats>, Option<BenchStats>) {
+    let first_input = input();
     let timer = Instant::now();
-    let result = {
+    let (result, memory) = {
         #[cfg(feature = "dhat-heap")]
         let _profiler = dhat::Profiler::new_heap();
 
-        func(input)
+        if memory {
+            MemoryStats::measure(|| func(first_input))
+        } else {
+            (func(first_input), None)
+        }
     };
     let base_time = timer.elapsed();
 
     hook(&result);
 
-    let run = if std::env::args().any(|x| x == "--time") {
-        bench(func, input, &base_time)
-    } else {
-        (base_time, 1)
-    };
+    // debug messages and frames are only recorded for the first run.
+    let stats =
+        bench_config.map(|config| without_traces(|| without_frames(|| bench(func, input, config))));
 
-    (result, run.0, run.1)
+    (result, base_time, memory, stats)
 }
 
-fn bench<I: Copy, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (Duration, u128) {
+fn bench<I, T>(func: impl Fn(I) -> T, input: impl Fn() -> I, config: &BenchConfig) -> BenchStats {
     let mut stdout = stdout();
 
     print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
     let _ = stdout.flush();
 
-    let bench_iterations =
-        (Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10)).clamp(10, 10000);
+    // warm up caches and branch predictors, this also gives a better estimate of a run's duration.
+    let warm_up_time = cmp::min(WARM_UP_TIME, config.time / 10);
+    let warm_up = Instant::now();
+    let mut warm_up_iterations: u32 = 0;
+    while warm_up_iterations == 0 || warm_up.elapsed() < warm_up_time {
+        black_box(func(black_box(input())));
+        warm_up_iterations += 1;
+    }
+    let base_time = warm_up.elapsed() / warm_up_iterations;
+
+    let bench_iterations = config.samples(base_time);
 
     let mut timers: Vec<Duration> = vec![];
 
     for _ in 0..bench_iterations {
+        // prepare the input outside of the timed region.
+        let input = black_box(input());
         let timer = Instant::now();
-        black_box(func(black_box(input)));
+        black_box(func(input));
         timers.push(timer.elapsed());
     }
 
-    (
-        #[allow(clippy::cast_possible_truncation)]
-        Duration::from_nanos(average_duration(&timers) as u64),
-        bench_iterations,
-    )
+    BenchStats::from_samples(&timers)
 }
 
-fn average_duration(numbers: &[Duration]) -> u128 {
-    numbers
-        .iter()
-        .map(std::time::Duration::as_nanos)
-        .sum::<u128>()
-        / numbers.len() as u128
+fn format_duration(duration: &Duration, stats: Option<&BenchStats>) -> String {
+    match stats {
+        Some(stats) => format!(
+            " ({} @ {} samples)",
+            format_nanos(stats.mean),
+            stats.samples
+        ),
+        None => format!(" ({duration:.1?})"),
+    }
 }
 
-fn format_duration(duration: &Duration, samples: u128) -> String {
-    if samples == 1 {
-        format!(" ({duration:.1?})")
-    } else {
-        format!(" ({duration:.1?} @ {samples} samples)")
+fn format_check(check: &AnswerCheck) -> String {
+    match check {
+        AnswerCheck::Unknown => String::new(),
+        AnswerCheck::Correct => " ✔".into(),
+        AnswerCheck::Incorrect { expected } if expected.contains('\n') => " ✘".into(),
+        AnswerCheck::Incorrect { expected } => format!(" ✘ expected {expected}"),
     }
 }
 
-fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str) {
+fn print_result(
+    output: Result<&Option<Answer>, &String>,
+    part: &str,
+    duration_str: &str,
+    check_str: &str,
+) {
     let is_intermediate_result = duration_str.is_empty();
 
-    match result {
-        Some(result) => {
-            if result.to_string().contains('\n') {
-                let str = format!("{part}: ▼ {duration_str}");
-                if is_intermediate_result {
-                    print!("{str}");
-                } else {
-                    print!("\r");
-                    println!("{str}");
-                    println!("{result}");
-                }
+    match output {
+        Ok(Some(answer @ Answer::Grid(_))) => {
+            // show the letters of a grid next to the part, when they can be read.
+            let letters = answer
+                .letters()
+                .map(|letters| format!(" {ANSI_BOLD}{letters}{ANSI_RESET}"))
+                .unwrap_or_default();
+            let str = format!("{part}: ▼{letters}{check_str} {duration_str}");
+            if is_intermediate_result {
+                print!("{str}");
             } else {
-                let str = format!("{part}: {ANSI_BOLD}{result}{ANSI_RESET}{duration_str}");
-                if is_intermediate_result {
-                    print!("{str}");
-                } else {
-                    print!("\r");
-                    println!("{str}");
-                }
+                print!("\r");
+                println!("{str}");
+                println!("{answer}");
+            }
+        }
+        Ok(Some(answer)) => {
+            let str = format!("{part}: {ANSI_BOLD}{answer}{ANSI_RESET}{check_str}{duration_str}");
+            if is_intermediate_result {
+                print!("{str}");
+            } else {
+                print!("\r");
+                println!("{str}");
             }
         }
-        None => {
+        Ok(None) => {
             if is_intermediate_result {
                 print!("{part}: ✖");
             } else {
                 print!("\r");
-                println!("{part}: ✖             ");
+                println!("{part}: ✖{check_str}             ");
+            }
+        }
+        Err(error) => {
+            let str = format!("{part}: ✖ {error}{check_str}{duration_str}");
+            if is_intermediate_result {
+                print!("{str}");
+            } else {
+                print!("\r");
+                println!("{str}");
             }
         }
     }
 }
 
-/// Parse the arguments passed to `solve` and try to submit one part of the solution if:
-///  1. we are in `--release` mode.
-///  2. aoc-cli is installed.
-fn submit_result<T: Display>(
-    result: T,
-    day: Day,
-    part: u8,
-) -> Option<Result<Output, aoc_cli::AocCommandError>> {
-    let args: Vec<String> = env::args().collect();
-
-    if !args.contains(&"--submit".into()) {
-        return None;
+/// Appends an entry to the report requested with `--report <path>`.
+fn write_report(args: &SolutionArgs, entry: ReportEntry) {
+    if let Some(path) = &args.report
+        && let Err(e) = entry.append_to(path)
+    {
+        eprintln!("failed to write the report to \"{}\": {e}", path.display());
     }
+}
 
-    if args.len() < 3 {
-        eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
-        process::exit(1);
+/// Try to submit one part of the solution if:
+///  1. it was requested with `--submit <part>`.
+///  2. the answer can be right according to earlier submissions in `data/submissions.json`.
+///  3. aoc-cli is installed, or the built-in client is selected with `AOC_CLIENT=native`.
+///
+/// Returns the outcome of the submission.
+fn submit_result(
+    answer: &Answer,
+    puzzle: PuzzleId,
+    part: Part,
+    args: &SolutionArgs,
+) -> Option<SubmitOutcome> {
+    if args.submit != Some(part) {
+        return None;
     }
 
-    let part_index = args.iter().position(|x| x == "--submit").unwrap() + 1;
+    let Some(result) = answer.submission() else {
+        eprintln!("could not read the letters of part {part}, please submit them manually.");
+        return None;
+    };
 
-    let Ok(part_submit) = args[part_index].parse::<u8>() else {
-        eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
-        process::exit(1);
+    let submissions = match Submissions::read_from_file() {
+        Ok(submissions) => submissions,
+        Err(e) => {
+            eprintln!("Not submitting part {part}: the earlier submissions are unknown, {e}");
+            return None;
+        }
     };
 
-    if part_submit != part {
+    if let Err(refused) = submissions.check(puzzle, part, &result) {
+        eprintln!("Not submitting part {part}: {refused}");
         return None;
     }
 
+    let backend = aoc_client::backend_or_exit();
+    let outcome = match backend {
+        AocBackend::AocCli => submit_with_aoc_cli(puzzle, part, &result)?,
+        AocBackend::Native => {
+            println!("Submitting result...");
+            match aoc_client::submit(puzzle, part, &result) {
+                Ok(outcome) => outcome,
+                Err(e) => {
+                    eprintln!("failed to submit part {part}: {e}");
+                    return None;
+                }
+            }
+        }
+    };
+    println!("{outcome}");
+
+    if let Some(verdict) = Verdict::from_outcome(outcome) {
+        record_submission(puzzle, part, &result, verdict);
+    }
+
+    // the description of part two is only served once part one is solved.
+    if outcome == SubmitOutcome::Correct && part == Part::One {
+        refresh_puzzle(puzzle, backend);
+    }
+
+    Some(outcome)
+}
+
+/// Submits an answer with aoc-cli and returns the outcome.
+fn submit_with_aoc_cli(puzzle: PuzzleId, part: Part, result: &str) -> Option<SubmitOutcome> {
     if aoc_cli::check().is_err() {
         eprintln!(
             "command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it."
@@ -163,5 +627,104 @@ fn submit_result<T: Display>(
     }
 
     println!("Submitting result via aoc-cli...");
-    Some(aoc_cli::submit(day, part, &result.to_string()))
+    match aoc_cli::submit(puzzle, part, result) {
+        Ok(outcome) => Some(outcome),
+        Err(e) => {
+            eprintln!("failed to submit part {part}: {e}");
+            None
+        }
+    }
+}
+
+/// Downloads the puzzle description again, so that it includes part two.
+fn refresh_puzzle(puzzle: PuzzleId, backend: AocBackend) {
+    let result = match backend {
+        AocBackend::AocCli => aoc_cli::download_puzzle(puzzle)
+            .map(|_| ())
+            .map_err(|e| e.to_string()),
+        AocBackend::Native => aoc_client::download_puzzle(puzzle).map_err(|e| e.to_string()),
+    };
+
+    match result {
+        Ok(()) => println!(
+            "🎄 Updated the puzzle in \"{}\" with part two.",
+            aoc_cli::get_puzzle_path(puzzle)
+        ),
+        Err(e) => eprintln!("failed to update the puzzle description: {e}"),
+    }
+}
+
+/// Stores a submitted answer and its verdict in the history of submissions.
+fn record_submission(puzzle: PuzzleId, part: Part, result: &str, verdict: Verdict) {
+    let mut submissions = match Submissions::read_from_file() {
+        Ok(submissions) => submissions,
+        Err(e) => {
+            eprintln!("failed to record the submission of part {part}: {e}");
+            return;
+        }
+    };
+    submissions.record(puzzle, part, result, verdict);
+
+    if let Err(e) = submissions.store_file() {
+        eprintln!("failed to record the submission of part {part}: {e}");
+    }
+}
+
+/// Stores the answer of a part in the known answers, so later runs can be checked against it.
+fn record_answer(puzzle: PuzzleId, part: Part, result: &str) {
+    let mut answers = match Answers::read_from_file() {
+        Ok(answers) => answers,
+        Err(e) => {
+            eprintln!("failed to record the answer of part {part}: {e}");
+            return;
+        }
+    };
+    answers.set(puzzle, part, result);
+
+    match answers.store_file() {
+        Ok(()) => println!("Recorded the answer of part {part} in \"data/answers.json\"."),
+        Err(e) => eprintln!("failed to record the answer of part {part}: {e}"),
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::error::Error;
+
+    use super::{PartOutput, ResultParseKind, ValueParseKind};
+    use crate::template::Answer;
+
+    #[test]
+    fn reads_part_outputs() {
+        assert_eq!(Some(42).to_answer(), Ok(Some(Answer::Integer(42))));
+        assert_eq!(None::<u64>.to_answer(), Ok(None));
+        assert_eq!(
+            Ok::<_, String>("ab").to_answer(),
+            Ok(Some(Answer::Text("ab".into())))
+        );
+        assert_eq!(
+            Err::<u64, _>("line 17: expected 3 comma-separated coordinates").to_answer(),
+            Err("line 17: expected 3 comma-separated coordinates".into())
+        );
+
+        let boxed: Result<u64, Box<dyn Error>> = "x".parse::<u64>().map_err(Into::into);
+        assert_eq!(
+            boxed.to_answer(),
+            Err("invalid digit found in string".into())
+        );
+    }
+
+    #[test]
+    fn reads_parse_outputs() {
+        let parsed = vec![1, 2];
+        assert_eq!((&parsed).parse_kind().into_parsed(parsed), Ok(vec![1, 2]));
+
+        let parsed: Result<u64, _> = "x".parse::<u64>();
+        assert_eq!(
+            (&parsed).parse_kind().into_parsed(parsed),
+            Err("invalid digit found in string".into())
+        );
+    }
 }
@@ -0,0 +1,335 @@
+/// Command-line arguments accepted by the individual solution binaries.
+use std::ffi::OsString;
+use std::io::{self, Read};
+use std::path::PathBuf;
+use std::sync::OnceLock;
+use std::{fs, process};
+
+use crate::template::trace::verbosity_from_args;
+use crate::template::{
+    BenchConfig, Part, PuzzleId, VisualizeMode, counts_allocations, data_file_path,
+    data_file_path_for_part, take_visualize,
+};
+
+const HELP: &str = "\
+Runs the solution of a single puzzle.
+
+USAGE:
+  cargo run --bin <year>_<day> -- [OPTIONS]
+
+OPTIONS:
+  --part <part>       Only run the given part (1 or 2).
+  --input <path>      Read the input from a file instead of `data/inputs`. Pass `-` to read stdin.
+  --example [n]       Read the input from `data/examples`, or the numbered example file `<day>-<n>.txt`.
+  --bench             Benchmark each part after running it.
+  --bench-time <time> Approximate time to spend benching a part, e.g. `500ms` or `30s`. [env: AOC_BENCH_TIME]
+  --min-samples <n>   Minimum number of samples to collect when benching. [env: AOC_MIN_SAMPLES]
+  --max-samples <n>   Maximum number of samples to collect when benching. [env: AOC_MAX_SAMPLES]
+  --memory            Measure the peak heap usage and allocations of each part. Needs the `count-allocations` feature.
+  --submit <part>     Submit the answer of the given part via aoc-cli, or the built-in client with `native`. [env: AOC_CLIENT]
+  --accept <part>     Record the answer of the given part as correct in `data/answers.json`.
+  --report <path>     Append a line of JSON with the answer and timings of each step to a file.
+  -v, --verbose       Print the messages of `aoc_debug!`. Repeat (`-vv`) to include `aoc_trace!`.
+  --trace <path>      Append the messages of `-v` to a file instead of printing them to stderr.
+  --visualize [mode]  Show the frames pushed to the `Visualizer`: `terminal` (default), `ppm` or `svg` images.
+  -h, --help          Print this help.
+";
+
+/// Where a solution reads its input from.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum InputSource {
+    /// The puzzle input in `data/inputs`.
+    #[default]
+    Puzzle,
+    /// The example in `data/examples`, optionally a numbered one.
+    Example(Option<u8>),
+    /// An arbitrary file.
+    File(PathBuf),
+    /// Standard input.
+    Stdin,
+}
+
+/// The default arguments run both parts against the puzzle input, without benching.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SolutionArgs {
+    pub part: Option<Part>,
+    pub input: InputSource,
+    pub bench: bool,
+    pub bench_config: BenchConfig,
+    /// Measure the heap usage of the first run of each step.
+    pub memory: bool,
+    pub submit: Option<Part>,
+    pub accept: Option<Part>,
+    /// Where to append the machine-readable report of the run.
+    pub report: Option<PathBuf>,
+    /// Level of the debug messages to print, `0` prints none.
+    pub verbosity: u8,
+    /// Where to append debug messages instead of stderr.
+    pub trace: Option<PathBuf>,
+    /// How to show the frames of a visualization, `None` records none.
+    pub visualize: Option<VisualizeMode>,
+    /// stdin can only be read once, but is shared by both parts.
+    stdin: OnceLock<String>,
+}
+
+impl SolutionArgs {
+    /// Parses the arguments of the current process, exiting with a message on invalid input.
+    pub fn from_env() -> Self {
+        match Self::parse(std::env::args_os().skip(1).collect()) {
+            Ok(args) => args,
+            Err(e) => {
+                eprintln!("Error: {e}");
+                eprintln!("Run with `--help` to see the available options.");
+                process::exit(1);
+            }
+        }
+    }
+
+    fn parse(mut raw_args: Vec<OsString>) -> Result<Self, Box<dyn std::error::Error>> {
+        let example = take_example(&mut raw_args)?;
+        let visualize = take_visualize(&mut raw_args);
+        let mut args = pico_args::Arguments::from_vec(raw_args);
+
+        if args.contains(["-h", "--help"]) {
+            print!("{HELP}");
+            process::exit(0);
+        }
+
+        let part = args.opt_value_from_str("--part")?;
+        let file: Option<String> = args.opt_value_from_str("--input")?;
+        // NOTE: `--time` is the flag used by earlier versions of the template.
+        let bench = args.contains("--bench") | args.contains("--time");
+        let bench_config = BenchConfig::from_args(&mut args)?;
+        let memory = args.contains("--memory");
+        let submit: Option<Part> = args.opt_value_from_str("--submit")?;
+        let accept: Option<Part> = args.opt_value_from_str("--accept")?;
+        let report: Option<PathBuf> = args.opt_value_from_str("--report")?;
+        let verbosity = verbosity_from_args(&mut args);
+        let trace: Option<PathBuf> = args.opt_value_from_str("--trace")?;
+
+        let remaining = args.finish();
+        if !remaining.is_empty() {
+            return Err(format!("unknown argument(s): {remaining:?}.").into());
+        }
+
+        let input = match (file, example) {
+            (Some(_), Some(_)) => return Err("`--input` and `--example` are exclusive.".into()),
+            (Some(file), None) if file == "-" => InputSource::Stdin,
+            (Some(file), None) => InputSource::File(file.into()),
+            (None, Some(n)) => InputSource::Example(n),
+            (None, None) => InputSource::Puzzle,
+        };
+
+        if memory && !counts_allocations() {
+            return Err(
+                "`--memory` needs the counting allocator, build with `--features count-allocations`."
+                    .into(),
+            );
+        }
+
+        if (submit.is_some() || accept.is_some()) && input != InputSource::Puzzle {
+            return Err("`--submit` and `--accept` can only be used with the puzzle input.".into());
+        }
+
+        for requested in [submit, accept].into_iter().flatten() {
+            if let Some(part) = part
+                && part != requested
+            {
+                return Err(format!(
+                    "cannot use part {requested}'s answer when only running part {part}."
+                )
+                .into());
+            }
+        }
+
+        Ok(Self {
+            part,
+            input,
+            bench,
+            bench_config,
+            memory,
+            submit,
+            accept,
+            report,
+            verbosity,
+            trace,
+            visualize,
+            stdin: OnceLock::new(),
+        })
+    }
+
+    /// Returns `true` if the given part should be run.
+    pub fn runs(&self, part: Part) -> bool {
+        self.part.is_none_or(|p| p == part)
+    }
+
+    /// Reads the input for a part.
+    pub fn read_input(&self, puzzle: PuzzleId, part: Part) -> Result<String, String> {
+        let path = match &self.input {
+            InputSource::Puzzle => data_file_path_for_part("inputs", puzzle, part),
+            InputSource::Example(None) => data_file_path_for_part("examples", puzzle, part),
+            InputSource::Example(Some(n)) => {
+                data_file_path("examples", puzzle, Some(&n.to_string()))
+            }
+            InputSource::File(path) => path.clone(),
+            InputSource::Stdin => {
+                return Ok(self.stdin.get_or_init(read_stdin).clone());
+            }
+        };
+
+        fs::read_to_string(&path)
+            .map_err(|e| format!("could not open input file \"{}\": {e}", path.display()))
+    }
+}
+
+fn read_stdin() -> String {
+    let mut buf = String::new();
+    if let Err(e) = io::stdin().read_to_string(&mut buf) {
+        eprintln!("could not read input from stdin: {e}");
+        process::exit(1);
+    }
+    buf
+}
+
+/// `--example` takes an optional value, which `pico_args` does not support.
+/// Removes the flag and its value (if it's a number) from the raw arguments.
+fn take_example(
+    raw_args: &mut Vec<OsString>,
+) -> Result<Option<Option<u8>>, Box<dyn std::error::Error>> {
+    let Some(index) = raw_args.iter().position(|arg| arg == "--example") else {
+        return Ok(None);
+    };
+
+    raw_args.remove(index);
+
+    let number = raw_args
+        .get(index)
+        .and_then(|arg| arg.to_str())
+        .filter(|arg| !arg.starts_with('-'))
+        .map(|arg| {
+            arg.parse::<u8>()
+                .map_err(|_| format!("expecting an example number, found `{arg}`."))
+        })
+        .transpose()?;
+
+    if number.is_some() {
+        raw_args.remove(index);
+    }
+
+    Ok(Some(number))
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::ffi::OsString;
+    use std::time::Duration;
+
+    use super::{InputSource, SolutionArgs};
+    use crate::template::{Part, VisualizeMode, counts_allocations};
+
+    fn parse(args: &[&str]) -> Result<SolutionArgs, Box<dyn std::error::Error>> {
+        SolutionArgs::parse(args.iter().map(OsString::from).collect())
+    }
+
+    #[test]
+    fn parses_defaults() {
+        let args = parse(&[]).unwrap();
+        assert_eq!(args, SolutionArgs::default());
+        assert_eq!(args.part, None);
+        assert_eq!(args.input, InputSource::Puzzle);
+        assert!(!args.bench);
+        assert_eq!(args.submit, None);
+        assert_eq!(args.accept, None);
+        assert_eq!(args.report, None);
+        assert_eq!(args.verbosity, 0);
+        assert!(args.runs(Part::One) && args.runs(Part::Two));
+    }
+
+    #[test]
+    fn parses_parts() {
+        let args = parse(&["--part", "2", "--bench"]).unwrap();
+        assert!(!args.runs(Part::One));
+        assert!(args.runs(Part::Two));
+        assert!(args.bench);
+    }
+
+    #[test]
+    fn parses_bench_config() {
+        let args = parse(&["--bench", "--bench-time", "50ms", "--max-samples", "100"]).unwrap();
+        assert_eq!(args.bench_config.time, Duration::from_millis(50));
+        assert_eq!(args.bench_config.max_samples, 100);
+    }
+
+    #[test]
+    fn parses_report_path() {
+        let args = parse(&["--report", "report.jsonl"]).unwrap();
+        assert_eq!(args.report, Some("report.jsonl".into()));
+    }
+
+    #[test]
+    fn parses_visualize_modes() {
+        assert_eq!(parse(&[]).unwrap().visualize, None);
+        assert_eq!(
+            parse(&["--visualize", "--example"]).unwrap().visualize,
+            Some(VisualizeMode::Terminal)
+        );
+        assert_eq!(
+            parse(&["--visualize", "ppm"]).unwrap().visualize,
+            Some(VisualizeMode::Ppm)
+        );
+    }
+
+    #[test]
+    fn parses_memory_flag() {
+        assert!(!parse(&[]).unwrap().memory);
+        // the flag is rejected if the counting allocator is not compiled in.
+        match parse(&["--memory"]) {
+            Ok(args) => assert!(args.memory && counts_allocations()),
+            Err(_) => assert!(!counts_allocations()),
+        }
+    }
+
+    #[test]
+    fn parses_verbosity() {
+        let args = parse(&["-vv", "--trace", "trace.log"]).unwrap();
+        assert_eq!(args.verbosity, 2);
+        assert_eq!(args.trace, Some("trace.log".into()));
+    }
+
+    #[test]
+    fn parses_input_sources() {
+        assert_eq!(
+            parse(&["--input", "foo.txt"]).unwrap().input,
+            InputSource::File("foo.txt".into())
+        );
+        assert_eq!(parse(&["--input", "-"]).unwrap().input, InputSource::Stdin);
+        assert_eq!(
+            parse(&["--example"]).unwrap().input,
+            InputSource::Example(None)
+        );
+        assert_eq!(
+            parse(&["--example", "2", "--part", "1"]).unwrap().input,
+            InputSource::Example(Some(2))
+        );
+        assert_eq!(
+            parse(&["--example", "--part", "1"]).unwrap().input,
+            InputSource::Example(None)
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_arguments() {
+        assert!(parse(&["--part", "3"]).is_err());
+        assert!(parse(&["--submit", "0"]).is_err());
+        assert!(parse(&["--example", "x"]).is_err());
+        assert!(parse(&["--input", "a.txt", "--example"]).is_err());
+        assert!(parse(&["--example", "--submit", "1"]).is_err());
+        assert!(parse(&["--part", "1", "--submit", "2"]).is_err());
+        assert!(parse(&["--input", "a.txt", "--accept", "1"]).is_err());
+        assert!(parse(&["--part", "2", "--accept", "1"]).is_err());
+        assert!(parse(&["--unknown"]).is_err());
+        assert!(parse(&["--bench-time", "soon"]).is_err());
+    }
+}
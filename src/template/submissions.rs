@@ -0,0 +1,396 @@
+use std::{collections::HashMap, error::Error, fmt::Display, fs, io, str::FromStr};
+use tinyjson::JsonValue;
+
+use crate::template::aoc_cli::{AnswerHint, SubmitOutcome};
+use crate::template::{Day, Part, PuzzleId, Year};
+
+static SUBMISSIONS_FILE_PATH: &str = "./data/submissions.json";
+
+/// The server's verdict on a submitted answer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    /// Wrong, without a hint whether the answer is too high or too low.
+    Wrong,
+    TooHigh,
+    TooLow,
+    /// The answer was not checked, because the previous submission was too recent.
+    RateLimited,
+}
+
+impl Verdict {
+    /// The verdict recorded for the outcome of a submission, `None` if the answer was not checked.
+    pub fn from_outcome(outcome: SubmitOutcome) -> Option<Self> {
+        match outcome {
+            SubmitOutcome::Correct => Some(Self::Correct),
+            SubmitOutcome::Incorrect { hint: None } => Some(Self::Wrong),
+            SubmitOutcome::Incorrect {
+                hint: Some(AnswerHint::TooHigh),
+            } => Some(Self::TooHigh),
+            SubmitOutcome::Incorrect {
+                hint: Some(AnswerHint::TooLow),
+            } => Some(Self::TooLow),
+            SubmitOutcome::Wait { .. } => Some(Self::RateLimited),
+            SubmitOutcome::AlreadySolved => None,
+        }
+    }
+
+    fn is_wrong(self) -> bool {
+        matches!(self, Self::Wrong | Self::TooHigh | Self::TooLow)
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Correct => "correct",
+            Self::Wrong => "wrong",
+            Self::TooHigh => "too high",
+            Self::TooLow => "too low",
+            Self::RateLimited => "rate-limited",
+        })
+    }
+}
+
+impl FromStr for Verdict {
+    type Err = VerdictFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "correct" => Ok(Self::Correct),
+            "wrong" => Ok(Self::Wrong),
+            "too high" => Ok(Self::TooHigh),
+            "too low" => Ok(Self::TooLow),
+            "rate-limited" => Ok(Self::RateLimited),
+            _ => Err(VerdictFromStrError),
+        }
+    }
+}
+
+/// An error which can be returned when parsing a [`Verdict`].
+#[derive(Debug)]
+pub struct VerdictFromStrError;
+
+impl Error for VerdictFromStrError {}
+
+impl Display for VerdictFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting one of `correct`, `wrong`, `too high`, `too low` or `rate-limited`")
+    }
+}
+
+/// A submitted answer and its verdict.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Submission {
+    pub puzzle: PuzzleId,
+    pub part: Part,
+    pub answer: String,
+    pub verdict: Verdict,
+}
+
+/// Represents the history of submissions, in the order they were made.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default)]
+pub struct Submissions {
+    pub data: Vec<Submission>,
+}
+
+/// The reason an answer is not submitted.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RefusedSubmission {
+    /// The same answer was submitted before and was wrong.
+    KnownWrong(Verdict),
+    /// The answer is at least as high as an answer that was too high.
+    TooHigh { bound: String },
+    /// The answer is at most as low as an answer that was too low.
+    TooLow { bound: String },
+}
+
+impl Display for RefusedSubmission {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::KnownWrong(Verdict::Wrong) => {
+                write!(f, "this answer was already submitted and is wrong.")
+            }
+            Self::KnownWrong(verdict) => {
+                write!(f, "this answer was already submitted and is {verdict}.")
+            }
+            Self::TooHigh { bound } => {
+                write!(
+                    f,
+                    "the answer must be lower than {bound}, which was too high."
+                )
+            }
+            Self::TooLow { bound } => {
+                write!(
+                    f,
+                    "the answer must be higher than {bound}, which was too low."
+                )
+            }
+        }
+    }
+}
+
+impl Submissions {
+    /// Dehydrate submissions to a JSON file.
+    pub fn store_file(&self) -> Result<(), io::Error> {
+        let json = JsonValue::from(self.clone());
+        let mut file = fs::File::create(SUBMISSIONS_FILE_PATH)?;
+        json.format_to(&mut file)
+    }
+
+    /// Rehydrate submissions from a JSON file. If not present, returns no submissions.
+    /// A file that can't be read is an error, so that it is never overwritten with an empty history.
+    pub fn read_from_file() -> Result<Self, String> {
+        Self::read_from_path(SUBMISSIONS_FILE_PATH)
+    }
+
+    fn read_from_path(path: &str) -> Result<Self, String> {
+        match fs::read_to_string(path) {
+            Ok(contents) => Submissions::try_from(contents)
+                .map_err(|e| format!("\"{path}\" could not be parsed: {e}")),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(format!("\"{path}\" could not be read: {e}")),
+        }
+    }
+
+    /// Records a submitted answer and its verdict.
+    pub fn record(&mut self, puzzle: PuzzleId, part: Part, answer: &str, verdict: Verdict) {
+        self.data.push(Submission {
+            puzzle,
+            part,
+            answer: answer.into(),
+            verdict,
+        });
+    }
+
+    /// Checks whether an answer can be right, given the earlier submissions of the part.
+    /// Bounds from too high or too low answers only apply to numeric answers.
+    pub fn check(
+        &self,
+        puzzle: PuzzleId,
+        part: Part,
+        answer: &str,
+    ) -> Result<(), RefusedSubmission> {
+        let submissions = self
+            .data
+            .iter()
+            .filter(|s| s.puzzle == puzzle && s.part == part && s.verdict.is_wrong());
+
+        let mut too_high: Option<(i128, &str)> = None;
+        let mut too_low: Option<(i128, &str)> = None;
+
+        for submission in submissions {
+            if submission.answer == answer {
+                return Err(RefusedSubmission::KnownWrong(submission.verdict));
+            }
+
+            let Ok(value) = submission.answer.parse::<i128>() else {
+                continue;
+            };
+            let bound = Some((value, submission.answer.as_str()));
+            match submission.verdict {
+                Verdict::TooHigh if too_high.is_none_or(|(high, _)| value < high) => {
+                    too_high = bound;
+                }
+                Verdict::TooLow if too_low.is_none_or(|(low, _)| value > low) => too_low = bound,
+                _ => {}
+            }
+        }
+
+        let Ok(value) = answer.parse::<i128>() else {
+            return Ok(());
+        };
+
+        match (too_high, too_low) {
+            (Some((high, bound)), _) if value >= high => Err(RefusedSubmission::TooHigh {
+                bound: bound.into(),
+            }),
+            (_, Some((low, bound))) if value <= low => Err(RefusedSubmission::TooLow {
+                bound: bound.into(),
+            }),
+            _ => Ok(()),
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<Submissions> for JsonValue {
+    fn from(value: Submissions) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "data".into(),
+            JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for Submissions {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json_data = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?
+            .get("data")
+            .ok_or("expected JSON document to have key `data`.")?
+            .get::<Vec<JsonValue>>()
+            .ok_or("expected `json.data` to be an array.")?;
+
+        Ok(Submissions {
+            data: json_data
+                .iter()
+                .map(Submission::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Submission> for JsonValue {
+    fn from(value: &Submission) -> Self {
+        let fields = [
+            ("year", value.puzzle.year.to_string()),
+            ("day", value.puzzle.day.to_string()),
+            ("part", value.part.to_string()),
+            ("answer", value.answer.clone()),
+            ("verdict", value.verdict.to_string()),
+        ];
+
+        JsonValue::Object(
+            fields
+                .into_iter()
+                .map(|(key, value)| (key.into(), JsonValue::String(value)))
+                .collect(),
+        )
+    }
+}
+
+impl TryFrom<&JsonValue> for Submission {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected submission to be a JSON object.")?;
+
+        let string = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<String>())
+                .ok_or(format!("Expected submission.{key} to be a string."))
+        };
+
+        let year = Year::from_str(string("year")?)
+            .map_err(|_| "Expected submission.year to be a Year struct.")?;
+        let day = Day::from_str(string("day")?)
+            .map_err(|_| "Expected submission.day to be a Day struct.")?;
+        let puzzle = PuzzleId::new(year, day)
+            .ok_or("Expected submission.day to be part of the year's event calendar.")?;
+
+        let part = Part::from_str(string("part")?)
+            .map_err(|_| "Expected submission.part to be `1` or `2`.")?;
+        let verdict = Verdict::from_str(string("verdict")?).map_err(|e| e.to_string())?;
+
+        Ok(Submission {
+            puzzle,
+            part,
+            answer: string("answer")?.clone(),
+            verdict,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{env, fs, process};
+
+    use super::{RefusedSubmission, Submissions, Verdict};
+    use crate::template::aoc_cli::{AnswerHint, SubmitOutcome};
+    use crate::template::test_fixtures::mock_submissions;
+    use crate::{puzzle, template::Part};
+
+    #[test]
+    fn reads_verdicts_from_outcomes() {
+        let too_low = SubmitOutcome::Incorrect {
+            hint: Some(AnswerHint::TooLow),
+        };
+        assert_eq!(Verdict::from_outcome(too_low), Some(Verdict::TooLow));
+        assert_eq!(
+            Verdict::from_outcome(SubmitOutcome::Incorrect { hint: None }),
+            Some(Verdict::Wrong)
+        );
+        assert_eq!(
+            Verdict::from_outcome(SubmitOutcome::Correct),
+            Some(Verdict::Correct)
+        );
+        assert_eq!(
+            Verdict::from_outcome(SubmitOutcome::Wait { seconds: Some(42) }),
+            Some(Verdict::RateLimited)
+        );
+        assert_eq!(Verdict::from_outcome(SubmitOutcome::AlreadySolved), None);
+    }
+
+    #[test]
+    fn refuses_known_wrong_answers() {
+        let submissions = mock_submissions();
+        let check = |part, answer| submissions.check(puzzle!(2025, 1), part, answer);
+
+        assert_eq!(
+            check(Part::One, "abc"),
+            Err(RefusedSubmission::KnownWrong(Verdict::Wrong))
+        );
+        assert_eq!(
+            check(Part::One, "100"),
+            Err(RefusedSubmission::KnownWrong(Verdict::TooHigh))
+        );
+        assert_eq!(
+            check(Part::One, "90"),
+            Err(RefusedSubmission::TooHigh { bound: "80".into() })
+        );
+        assert_eq!(
+            check(Part::One, "-3"),
+            Err(RefusedSubmission::TooLow { bound: "20".into() })
+        );
+        assert_eq!(check(Part::One, "50"), Ok(()));
+        assert_eq!(check(Part::One, "xyz"), Ok(()));
+        assert_eq!(check(Part::Two, "100"), Ok(()));
+        assert_eq!(
+            submissions.check(puzzle!(2025, 2), Part::One, "100"),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn round_trips_submissions() {
+        let submissions = mock_submissions();
+        let json = tinyjson::JsonValue::from(submissions.clone())
+            .stringify()
+            .unwrap();
+        assert_eq!(Submissions::try_from(json).unwrap().data, submissions.data);
+
+        let json = r#"{ "data": [{ "year": "2025", "day": "01", "part": "1", "answer": "1", "verdict": "maybe" }] }"#;
+        assert!(Submissions::try_from(json.to_string()).is_err());
+    }
+
+    #[test]
+    fn reads_only_valid_files() {
+        let path = env::temp_dir().join(format!("aoc-submissions-test-{}.json", process::id()));
+        let path = path.to_str().unwrap();
+
+        assert!(Submissions::read_from_path(path).unwrap().data.is_empty());
+
+        fs::write(path, "{ \"data\": [{ \"year\": \"2025\" }] }").unwrap();
+        let result = Submissions::read_from_path(path);
+        fs::remove_file(path).unwrap();
+        assert!(result.is_err());
+    }
+}
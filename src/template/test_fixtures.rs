@@ -0,0 +1,81 @@
+//! Fixtures shared by the tests of the template modules.
+
+use crate::{
+    puzzle,
+    template::report::{ReportEntry, ReportStep},
+    template::submissions::{Submissions, Verdict},
+    template::timings::{Timing, Timings},
+    template::{BenchStats, MemoryUsage, Part, RunStatus},
+};
+
+/// Statistics of a benchmark with the given mean, whose p95 is twice the mean.
+pub(crate) fn mock_stats(mean: f64) -> BenchStats {
+    BenchStats {
+        samples: 10,
+        outliers: 1,
+        mean,
+        min: mean / 2.0,
+        median: mean,
+        p95: mean * 2.0,
+        max: mean * 3.0,
+        stddev: 0.0,
+    }
+}
+
+/// Timings of 2025 days 1, 2 and 4, which took 10ms to 50ms per part.
+pub(crate) fn mock_timings() -> Timings {
+    Timings {
+        data: vec![
+            Timing {
+                puzzle: puzzle!(2025, 1),
+                parse: None,
+                part_1: Some(mock_stats(10e6)),
+                part_2: Some(mock_stats(20e6)),
+                memory: MemoryUsage::default(),
+                status: RunStatus::Completed,
+            },
+            Timing {
+                puzzle: puzzle!(2025, 2),
+                parse: None,
+                part_1: Some(mock_stats(30e6)),
+                part_2: Some(mock_stats(40e6)),
+                memory: MemoryUsage::default(),
+                status: RunStatus::Completed,
+            },
+            Timing {
+                puzzle: puzzle!(2025, 4),
+                parse: None,
+                part_1: Some(mock_stats(40e6)),
+                part_2: Some(mock_stats(50e6)),
+                memory: MemoryUsage::default(),
+                status: RunStatus::Completed,
+            },
+        ],
+    }
+}
+
+/// A report entry answering `42`, benched with the given mean if there is one.
+pub(crate) fn mock_entry(step: ReportStep, mean: Option<f64>) -> ReportEntry {
+    ReportEntry {
+        step,
+        answer: Some("42".into()),
+        matches_known: None,
+        nanos: 1.0,
+        stats: mean.map(mock_stats),
+        memory: None,
+        error: None,
+        panic: None,
+    }
+}
+
+/// Submissions of 2025 day 1, whose wrong answers to part one bound it between 20 and 80.
+pub(crate) fn mock_submissions() -> Submissions {
+    let mut submissions = Submissions::default();
+    submissions.record(puzzle!(2025, 1), Part::One, "100", Verdict::TooHigh);
+    submissions.record(puzzle!(2025, 1), Part::One, "80", Verdict::TooHigh);
+    submissions.record(puzzle!(2025, 1), Part::One, "20", Verdict::TooLow);
+    submissions.record(puzzle!(2025, 1), Part::One, "abc", Verdict::Wrong);
+    submissions.record(puzzle!(2025, 1), Part::One, "50", Verdict::RateLimited);
+    submissions.record(puzzle!(2025, 1), Part::Two, "7", Verdict::Correct);
+    submissions
+}
@@ -1,17 +1,40 @@
-use std::{collections::HashMap, fs, io::Error, str::FromStr};
+use std::{
+    collections::HashMap,
+    fs,
+    io::{Error, ErrorKind},
+    str::FromStr,
+};
 use tinyjson::JsonValue;
 
-use crate::template::Day;
+use crate::template::{
+    BenchFigure, BenchStats, Day, MemoryStats, MemoryUsage, PuzzleId, RunStatus, Year,
+};
 
 static TIMINGS_FILE_PATH: &str = "./data/timings.json";
 
-/// Represents benchmark times for a single day.
+/// Represents benchmark times for a single puzzle.
 #[derive(Clone, Debug)]
 pub struct Timing {
-    pub day: Day,
-    pub part_1: Option<String>,
-    pub part_2: Option<String>,
-    pub total_nanos: f64,
+    pub puzzle: PuzzleId,
+    /// Only set for solutions with a shared `parse` function.
+    pub parse: Option<BenchStats>,
+    pub part_1: Option<BenchStats>,
+    pub part_2: Option<BenchStats>,
+    /// Only set when timed with `--memory`.
+    pub memory: MemoryUsage,
+    /// How the process of the day ended, e.g. if it exceeded a timeout.
+    pub status: RunStatus,
+}
+
+impl Timing {
+    /// Sum up the given figure of parsing and both parts as nanos.
+    pub fn total_nanos(&self, figure: BenchFigure) -> f64 {
+        [self.parse, self.part_1, self.part_2]
+            .iter()
+            .flatten()
+            .map(|stats| stats.figure(figure))
+            .sum()
+    }
 }
 
 /// Represents benchmark times for a set of days.
@@ -30,11 +53,18 @@ impl Timings {
     }
 
     /// Rehydrate timings from a JSON file. If not present, returns empty timings.
-    pub fn read_from_file() -> Self {
-        fs::read_to_string(TIMINGS_FILE_PATH)
-            .map_err(|x| x.to_string())
-            .and_then(Timings::try_from)
-            .unwrap_or_default()
+    /// A file that can't be read is an error, so that it is never overwritten with fewer timings.
+    pub fn read_from_file() -> Result<Self, String> {
+        Self::read_from_path(TIMINGS_FILE_PATH)
+    }
+
+    fn read_from_path(path: &str) -> Result<Self, String> {
+        match fs::read_to_string(path) {
+            Ok(contents) => Timings::try_from(contents)
+                .map_err(|e| format!("\"{path}\" could not be parsed: {e}")),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(format!("\"{path}\" could not be read: {e}")),
+        }
     }
 
     /// Merge two sets of timings, overwriting `self` with `other` if present.
@@ -46,24 +76,24 @@ impl Timings {
         }
 
         for timing in &self.data {
-            if !data.iter().any(|t| t.day == timing.day) {
+            if !data.iter().any(|t| t.puzzle == timing.puzzle) {
                 data.push(timing.clone());
             }
         }
 
-        data.sort_unstable_by(|a, b| a.day.cmp(&b.day));
+        data.sort_unstable_by_key(|a| a.puzzle);
         Timings { data }
     }
 
-    /// Sum up total duration of timings as millis.
-    pub fn total_millis(&self) -> f64 {
-        self.data.iter().map(|x| x.total_nanos).sum::<f64>() / 1_000_000_f64
+    /// Sum up the given figure of all timings as millis.
+    pub fn total_millis(&self, figure: BenchFigure) -> f64 {
+        self.data.iter().map(|x| x.total_nanos(figure)).sum::<f64>() / 1_000_000_f64
     }
 
-    pub fn is_day_complete(&self, day: Day) -> bool {
+    pub fn is_day_complete(&self, puzzle: PuzzleId) -> bool {
         self.data
             .iter()
-            .any(|t| t.day == day && t.part_1.is_some() && t.part_2.is_some())
+            .any(|t| t.puzzle == puzzle && t.part_1.is_some() && t.part_2.is_some())
     }
 }
 
@@ -111,11 +141,21 @@ impl From<&Timing> for JsonValue {
     fn from(value: &Timing) -> Self {
         let mut map: HashMap<String, JsonValue> = HashMap::new();
 
-        map.insert("day".into(), JsonValue::String(value.day.to_string()));
-        map.insert("total_nanos".into(), JsonValue::Number(value.total_nanos));
+        map.insert(
+            "year".into(),
+            JsonValue::String(value.puzzle.year.to_string()),
+        );
+        map.insert(
+            "day".into(),
+            JsonValue::String(value.puzzle.day.to_string()),
+        );
+
+        if let Some(parse) = &value.parse {
+            map.insert("parse".into(), JsonValue::from(parse));
+        }
 
-        let part_1 = value.part_1.clone().map(JsonValue::String);
-        let part_2 = value.part_2.clone().map(JsonValue::String);
+        let part_1 = value.part_1.as_ref().map(JsonValue::from);
+        let part_2 = value.part_2.as_ref().map(JsonValue::from);
 
         map.insert(
             "part_1".into(),
@@ -133,6 +173,14 @@ impl From<&Timing> for JsonValue {
             },
         );
 
+        if !value.memory.is_empty() {
+            map.insert("memory".into(), JsonValue::from(&value.memory));
+        }
+
+        if value.status != RunStatus::Completed {
+            map.insert("status".into(), JsonValue::String(value.status.to_string()));
+        }
+
         JsonValue::Object(map)
     }
 }
@@ -145,82 +193,256 @@ impl TryFrom<&JsonValue> for Timing {
             .get::<HashMap<String, JsonValue>>()
             .ok_or("Expected timing to be a JSON object.")?;
 
+        let year = json
+            .get("year")
+            .and_then(|v| v.get::<String>())
+            .and_then(|year| Year::from_str(year).ok())
+            .ok_or("Expected timing.year to be a Year struct.")?;
+
         let day = json
             .get("day")
             .and_then(|v| v.get::<String>())
             .and_then(|day| Day::from_str(day).ok())
             .ok_or("Expected timing.day to be a Day struct.")?;
 
+        // NOTE: `parse` is optional, timings of solutions without a `parse` function omit it.
+        let parse = json
+            .get("parse")
+            .filter(|v| !v.is_null())
+            .map(BenchStats::try_from)
+            .transpose()
+            .map_err(|_| "Expected timing.parse to be benchmark statistics.")?;
+
         let part_1 = json
             .get("part_1")
-            .map(|v| if v.is_null() { None } else { v.get::<String>() })
-            .ok_or("Expected timing.part_1 to be null or string.")?;
+            .map(|v| {
+                if v.is_null() {
+                    Ok(None)
+                } else {
+                    BenchStats::try_from(v).map(Some)
+                }
+            })
+            .and_then(Result::ok)
+            .ok_or("Expected timing.part_1 to be null or benchmark statistics.")?;
 
         let part_2 = json
             .get("part_2")
-            .map(|v| if v.is_null() { None } else { v.get::<String>() })
-            .ok_or("Expected timing.part_2 to be null or string.")?;
+            .map(|v| {
+                if v.is_null() {
+                    Ok(None)
+                } else {
+                    BenchStats::try_from(v).map(Some)
+                }
+            })
+            .and_then(Result::ok)
+            .ok_or("Expected timing.part_2 to be null or benchmark statistics.")?;
+
+        // NOTE: `memory` is optional, timings without `--memory` omit it.
+        let memory = json
+            .get("memory")
+            .map(MemoryUsage::try_from)
+            .transpose()?
+            .unwrap_or_default();
+
+        // NOTE: `status` is optional, timings of completed days omit it.
+        let status = match json.get("status") {
+            None => RunStatus::Completed,
+            Some(v) => v
+                .get::<String>()
+                .and_then(|status| RunStatus::from_str(status).ok())
+                .ok_or("Expected timing.status to be a run status.")?,
+        };
 
-        let total_nanos = json
-            .get("total_nanos")
-            .and_then(|v| v.get::<f64>().copied())
-            .ok_or("Expected timing.total_nanos to be a number.")?;
+        let puzzle = PuzzleId::new(year, day)
+            .ok_or("Expected timing.day to be part of the year's event calendar.")?;
 
         Ok(Timing {
-            day,
-            part_1: part_1.cloned(),
-            part_2: part_2.cloned(),
-            total_nanos,
+            puzzle,
+            parse,
+            part_1,
+            part_2,
+            memory,
+            status,
         })
     }
 }
 
 /* -------------------------------------------------------------------------- */
 
-#[cfg(feature = "test_lib")]
-mod tests {
-    use crate::day;
-
-    use super::{Timing, Timings};
-
-    fn get_mock_timings() -> Timings {
-        Timings {
-            data: vec![
-                Timing {
-                    day: day!(1),
-                    part_1: Some("10ms".into()),
-                    part_2: Some("20ms".into()),
-                    total_nanos: 3e+10,
-                },
-                Timing {
-                    day: day!(2),
-                    part_1: Some("30ms".into()),
-                    part_2: Some("40ms".into()),
-                    total_nanos: 7e+10,
-                },
-                Timing {
-                    day: day!(4),
-                    part_1: Some("40ms".into()),
-                    part_2: None,
-                    total_nanos: 4e+10,
-                },
-            ],
-        }
+impl From<&BenchStats> for JsonValue {
+    fn from(value: &BenchStats) -> Self {
+        #[allow(clippy::cast_precision_loss)]
+        let fields = [
+            ("samples", value.samples as f64),
+            ("outliers", value.outliers as f64),
+            ("mean", value.mean),
+            ("min", value.min),
+            ("median", value.median),
+            ("p95", value.p95),
+            ("max", value.max),
+            ("stddev", value.stddev),
+        ];
+
+        JsonValue::Object(
+            fields
+                .into_iter()
+                .map(|(key, value)| (key.into(), JsonValue::Number(value)))
+                .collect(),
+        )
+    }
+}
+
+impl TryFrom<&JsonValue> for BenchStats {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected benchmark statistics to be a JSON object.")?;
+
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>().copied())
+                .ok_or(format!("Expected statistics.{key} to be a number."))
+        };
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        Ok(BenchStats {
+            samples: number("samples")? as u64,
+            outliers: number("outliers")? as u64,
+            mean: number("mean")?,
+            min: number("min")?,
+            median: number("median")?,
+            p95: number("p95")?,
+            max: number("max")?,
+            stddev: number("stddev")?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&MemoryUsage> for JsonValue {
+    fn from(value: &MemoryUsage) -> Self {
+        let steps = [
+            ("parse", value.parse),
+            ("part_1", value.part_1),
+            ("part_2", value.part_2),
+        ];
+
+        JsonValue::Object(
+            steps
+                .into_iter()
+                .filter_map(|(key, stats)| Some((key.into(), JsonValue::from(&stats?))))
+                .collect(),
+        )
+    }
+}
+
+impl TryFrom<&JsonValue> for MemoryUsage {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected timing.memory to be a JSON object.")?;
+
+        let step = |key: &str| json.get(key).map(MemoryStats::try_from).transpose();
+
+        Ok(MemoryUsage {
+            parse: step("parse")?,
+            part_1: step("part_1")?,
+            part_2: step("part_2")?,
+        })
+    }
+}
+
+impl From<&MemoryStats> for JsonValue {
+    fn from(value: &MemoryStats) -> Self {
+        #[allow(clippy::cast_precision_loss)]
+        let fields = [
+            ("peak_bytes", value.peak_bytes as f64),
+            ("allocations", value.allocations as f64),
+        ];
+
+        JsonValue::Object(
+            fields
+                .into_iter()
+                .map(|(key, value)| (key.into(), JsonValue::Number(value)))
+                .collect(),
+        )
+    }
+}
+
+impl TryFrom<&JsonValue> for MemoryStats {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected memory statistics to be a JSON object.")?;
+
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>().copied())
+                .ok_or(format!("Expected memory.{key} to be a number."))
+        };
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        Ok(MemoryStats {
+            peak_bytes: number("peak_bytes")? as u64,
+            allocations: number("allocations")? as u64,
+        })
     }
+}
 
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
     mod deserialization {
-        use crate::{day, template::timings::Timings};
+        use std::{env, fs, process};
+
+        use crate::{
+            puzzle,
+            template::{BenchFigure, RunStatus, timings::Timings},
+        };
 
         #[test]
         fn handles_json_timings() {
-            let json = r#"{ "data": [{ "day": "01", "part_1": "1ms", "part_2": null, "total_nanos": 1000000000 }] }"#.to_string();
+            let json = r#"{ "data": [{ "year": "2025", "day": "01", "part_1": { "samples": 10, "outliers": 1, "mean": 1000000, "min": 900000, "median": 950000, "p95": 1200000, "max": 1500000, "stddev": 50000 }, "part_2": null }] }"#.to_string();
             let timings = Timings::try_from(json).unwrap();
             assert_eq!(timings.data.len(), 1);
             let timing = timings.data.first().unwrap();
-            assert_eq!(timing.day, day!(1));
-            assert_eq!(timing.part_1, Some("1ms".to_string()));
+            assert_eq!(timing.puzzle, puzzle!(2025, 1));
+            let part_1 = timing.part_1.unwrap();
+            assert_eq!(part_1.samples, 10);
+            assert_eq!(part_1.outliers, 1);
+            assert_eq!(part_1.median, 950_000_f64);
             assert_eq!(timing.part_2, None);
-            assert_eq!(timing.total_nanos, 1_000_000_000_f64);
+            assert_eq!(timing.total_nanos(BenchFigure::Mean), 1_000_000_f64);
+            assert_eq!(timing.total_nanos(BenchFigure::P95), 1_200_000_f64);
+            assert_eq!(timing.parse, None);
+        }
+
+        #[test]
+        fn handles_parse_timings() {
+            let json = r#"{ "data": [{ "year": "2025", "day": "01", "parse": { "samples": 10, "outliers": 0, "mean": 500, "min": 500, "median": 500, "p95": 500, "max": 500, "stddev": 0 }, "part_1": null, "part_2": null }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let timing = timings.data.first().unwrap();
+            assert_eq!(timing.parse.unwrap().mean, 500_f64);
+            assert_eq!(timing.total_nanos(BenchFigure::Mean), 500_f64);
+        }
+
+        #[test]
+        fn handles_run_statuses() {
+            let json = r#"{ "data": [{ "year": "2025", "day": "01", "part_1": null, "part_2": null, "status": "timed out" }, { "year": "2025", "day": "02", "part_1": null, "part_2": null }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            assert_eq!(timings.data[0].status, RunStatus::TimedOut);
+            assert_eq!(timings.data[1].status, RunStatus::Completed);
+
+            let json = r#"{ "data": [{ "year": "2025", "day": "01", "part_1": null, "part_2": null, "status": "crashed" }] }"#.to_string();
+            assert!(Timings::try_from(json).is_err());
         }
 
         #[test]
@@ -237,22 +459,92 @@ mod tests {
             Timings::try_from(json).unwrap();
         }
 
+        #[test]
+        #[should_panic]
+        fn panics_for_timings_without_year() {
+            let json =
+                r#"{ "data": [{ "day": "01", "part_1": null, "part_2": null }] }"#.to_string();
+            Timings::try_from(json).unwrap();
+        }
+
+        #[test]
+        #[should_panic]
+        fn panics_for_days_outside_of_event_calendar() {
+            let json =
+                r#"{ "data": [{ "year": "2025", "day": "13", "part_1": null, "part_2": null }] }"#
+                    .to_string();
+            Timings::try_from(json).unwrap();
+        }
+
+        #[test]
+        fn reads_only_valid_files() {
+            let path = env::temp_dir().join(format!("aoc-timings-test-{}.json", process::id()));
+            let path = path.to_str().unwrap();
+
+            assert!(Timings::read_from_path(path).unwrap().data.is_empty());
+
+            let json = r#"{ "data": [{ "year": "2025", "day": "01", "part_1": "1ms", "part_2": null, "total_nanos": 1000000 }] }"#;
+            fs::write(path, json).unwrap();
+            let result = Timings::read_from_path(path);
+            fs::remove_file(path).unwrap();
+            assert!(result.is_err());
+        }
+
+        #[test]
+        #[should_panic]
+        fn panics_for_legacy_timings() {
+            let json = r#"{ "data": [{ "year": "2025", "day": "01", "part_1": "1ms", "part_2": null, "total_nanos": 1000000 }] }"#.to_string();
+            Timings::try_from(json).unwrap();
+        }
+
         #[test]
         #[should_panic]
         fn panics_for_malformed_timings() {
-            let json = r#"{ "data": [{ "day": "01" }, { "day": "26" }, { "day": "02", "part_2": null, "total_nanos": 0 }] }"#.to_string();
+            let json = r#"{ "data": [{ "year": "2025", "day": "01" }, { "year": "2025", "day": "26" }, { "year": "2025", "day": "02", "part_2": null }] }"#.to_string();
             Timings::try_from(json).unwrap();
         }
     }
 
     mod serialization {
-        use super::get_mock_timings;
+        use crate::template::test_fixtures::mock_timings;
+        use crate::template::{MemoryStats, RunStatus, timings::Timings};
         use std::collections::HashMap;
         use tinyjson::JsonValue;
 
+        #[test]
+        fn serializes_memory_usage() {
+            let mut timings = mock_timings();
+            timings.data[0].memory.part_2 = Some(MemoryStats {
+                peak_bytes: 1_048_576,
+                allocations: 3,
+            });
+
+            let json = JsonValue::from(timings).stringify().unwrap();
+            assert_eq!(json.matches(r#""memory""#).count(), 1);
+
+            let timings = Timings::try_from(json).unwrap();
+            assert_eq!(timings.data[0].memory.parse, None);
+            assert_eq!(timings.data[0].memory.part_1, None);
+            assert_eq!(timings.data[0].memory.part_2.unwrap().peak_bytes, 1_048_576);
+            assert!(timings.data[1].memory.is_empty());
+        }
+
+        #[test]
+        fn serializes_run_statuses() {
+            let mut timings = mock_timings();
+            timings.data[2].status = RunStatus::Killed;
+
+            let json = JsonValue::from(timings).stringify().unwrap();
+            assert_eq!(json.matches(r#""status""#).count(), 1);
+
+            let timings = Timings::try_from(json).unwrap();
+            assert_eq!(timings.data[2].status, RunStatus::Killed);
+            assert_eq!(timings.data[0].status, RunStatus::Completed);
+        }
+
         #[test]
         fn serializes_timings() {
-            let timings = get_mock_timings();
+            let timings = mock_timings();
             let value = JsonValue::try_from(timings).unwrap();
             assert_eq!(
                 value
@@ -270,112 +562,146 @@ mod tests {
 
     mod is_day_complete {
         use crate::{
-            day,
+            puzzle,
             template::timings::{Timing, Timings},
+            template::{MemoryUsage, RunStatus},
         };
 
+        use crate::template::test_fixtures::mock_stats;
+
         #[test]
         fn handles_completed_days() {
             let timings = Timings {
                 data: vec![Timing {
-                    day: day!(1),
-                    part_1: Some("1ms".into()),
-                    part_2: Some("2ms".into()),
-                    total_nanos: 3_000_000_000_f64,
+                    puzzle: puzzle!(2025, 1),
+                    parse: None,
+                    part_1: Some(mock_stats(1e6)),
+                    part_2: Some(mock_stats(2e6)),
+                    memory: MemoryUsage::default(),
+                    status: RunStatus::Completed,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(day!(1)), true);
+            assert_eq!(timings.is_day_complete(puzzle!(2025, 1)), true);
         }
 
         #[test]
         fn handles_partial_days() {
             let timings = Timings {
                 data: vec![Timing {
-                    day: day!(1),
-                    part_1: Some("1ms".into()),
+                    puzzle: puzzle!(2025, 1),
+                    parse: None,
+                    part_1: Some(mock_stats(1e6)),
                     part_2: None,
-                    total_nanos: 1_000_000_000_f64,
+                    memory: MemoryUsage::default(),
+                    status: RunStatus::Completed,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(day!(1)), false);
+            assert_eq!(timings.is_day_complete(puzzle!(2025, 1)), false);
         }
 
         #[test]
         fn handles_uncompleted_days() {
             let timings = Timings {
                 data: vec![Timing {
-                    day: day!(1),
+                    puzzle: puzzle!(2025, 1),
+                    parse: None,
                     part_1: None,
                     part_2: None,
-                    total_nanos: 0.0,
+                    memory: MemoryUsage::default(),
+                    status: RunStatus::Completed,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(day!(1)), false);
+            assert_eq!(timings.is_day_complete(puzzle!(2025, 1)), false);
         }
     }
 
     mod merge {
         use crate::{
-            day,
+            puzzle,
             template::timings::{Timing, Timings},
+            template::{MemoryUsage, RunStatus},
         };
 
-        use super::get_mock_timings;
+        use crate::template::test_fixtures::mock_timings;
 
         #[test]
         fn handles_disjunct_timings() {
-            let timings = get_mock_timings();
+            let timings = mock_timings();
             let other = Timings {
                 data: vec![Timing {
-                    day: day!(3),
+                    puzzle: puzzle!(2025, 3),
+                    parse: None,
                     part_1: None,
                     part_2: None,
-                    total_nanos: 0_f64,
+                    memory: MemoryUsage::default(),
+                    status: RunStatus::Completed,
                 }],
             };
             let merged = timings.merge(&other);
             assert_eq!(merged.data.len(), 4);
-            assert_eq!(merged.data[0].day, day!(1));
-            assert_eq!(merged.data[1].day, day!(2));
-            assert_eq!(merged.data[2].day, day!(3));
-            assert_eq!(merged.data[3].day, day!(4));
+            assert_eq!(merged.data[0].puzzle, puzzle!(2025, 1));
+            assert_eq!(merged.data[1].puzzle, puzzle!(2025, 2));
+            assert_eq!(merged.data[2].puzzle, puzzle!(2025, 3));
+            assert_eq!(merged.data[3].puzzle, puzzle!(2025, 4));
         }
 
         #[test]
         fn handles_overlapping_timings() {
-            let timings = get_mock_timings();
+            let timings = mock_timings();
 
             let other = Timings {
                 data: vec![Timing {
-                    day: day!(2),
+                    puzzle: puzzle!(2025, 2),
+                    parse: None,
                     part_1: None,
                     part_2: None,
-                    total_nanos: 0_f64,
+                    memory: MemoryUsage::default(),
+                    status: RunStatus::Completed,
                 }],
             };
             let merged = timings.merge(&other);
 
             assert_eq!(merged.data.len(), 3);
-            assert_eq!(merged.data[0].day, day!(1));
-            assert_eq!(merged.data[1].day, day!(2));
-            assert_eq!(merged.data[1].total_nanos, 0_f64);
-            assert_eq!(merged.data[2].day, day!(4));
+            assert_eq!(merged.data[0].puzzle, puzzle!(2025, 1));
+            assert_eq!(merged.data[1].puzzle, puzzle!(2025, 2));
+            assert_eq!(merged.data[1].part_1, None);
+            assert_eq!(merged.data[2].puzzle, puzzle!(2025, 4));
+        }
+
+        #[test]
+        fn handles_timings_of_other_years() {
+            let timings = mock_timings();
+            let other = Timings {
+                data: vec![Timing {
+                    puzzle: puzzle!(2024, 2),
+                    parse: None,
+                    part_1: None,
+                    part_2: None,
+                    memory: MemoryUsage::default(),
+                    status: RunStatus::Completed,
+                }],
+            };
+            let merged = timings.merge(&other);
+            assert_eq!(merged.data.len(), 4);
+            assert_eq!(merged.data[0].puzzle, puzzle!(2024, 2));
+            assert_eq!(merged.data[1].puzzle, puzzle!(2025, 1));
+            assert_eq!(merged.data[2].puzzle, puzzle!(2025, 2));
         }
 
         #[test]
         fn handles_empty_timings() {
             let timings = Timings::default();
-            let other = get_mock_timings();
+            let other = mock_timings();
             let merged = timings.merge(&other);
             assert_eq!(merged.data.len(), 3);
         }
 
         #[test]
         fn handles_empty_other_timings() {
-            let timings = get_mock_timings();
+            let timings = mock_timings();
             let other = Timings::default();
             let merged = timings.merge(&other);
             assert_eq!(merged.data.len(), 3);
@@ -0,0 +1,120 @@
+/// Diagnostics of solutions, printed with `aoc_debug!` and `aoc_trace!` when a solution binary runs with `-v` or `-vv`.
+/// Traces go to stderr, or to the file passed with `--trace <path>`, so they never mix with the answers.
+use std::fmt;
+use std::fs::{File, OpenOptions};
+use std::io::Write;
+use std::path::Path;
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::{Mutex, PoisonError};
+
+static VERBOSITY: AtomicU8 = AtomicU8::new(0);
+static TRACE_FILE: Mutex<Option<File>> = Mutex::new(None);
+
+/// Prints a diagnostic message of a solution when its binary runs with `-v`, like `eprintln!`.
+/// Nothing is printed by default, in tests or while benching.
+#[macro_export]
+macro_rules! aoc_debug {
+    ($($arg:tt)*) => {
+        if $crate::template::trace::is_enabled(1) {
+            $crate::template::trace::write(format_args!($($arg)*));
+        }
+    };
+}
+
+/// Like [`aoc_debug!`], for detailed messages that are only printed with `-vv`.
+#[macro_export]
+macro_rules! aoc_trace {
+    ($($arg:tt)*) => {
+        if $crate::template::trace::is_enabled(2) {
+            $crate::template::trace::write(format_args!($($arg)*));
+        }
+    };
+}
+
+/// Returns `true` if messages of the given verbosity level are printed.
+pub fn is_enabled(level: u8) -> bool {
+    VERBOSITY.load(Ordering::Relaxed) >= level
+}
+
+#[doc(hidden)]
+pub fn write(args: fmt::Arguments) {
+    let mut file = TRACE_FILE.lock().unwrap_or_else(PoisonError::into_inner);
+    match file.as_mut() {
+        Some(file) => {
+            let _ = writeln!(file, "{args}");
+        }
+        None => eprintln!("{args}"),
+    }
+}
+
+/// Counts the `-v` and `--verbose` flags, where `-vv` counts twice.
+pub fn verbosity_from_args(args: &mut pico_args::Arguments) -> u8 {
+    let mut verbosity: u8 = 0;
+    while args.contains(["-v", "--verbose"]) {
+        verbosity = verbosity.saturating_add(1);
+    }
+    while args.contains("-vv") {
+        verbosity = verbosity.saturating_add(2);
+    }
+    verbosity
+}
+
+/// Enables traces up to the given verbosity while it is alive.
+pub(crate) struct TraceScope;
+
+impl TraceScope {
+    /// Traces are appended to `path` if given, and printed to stderr otherwise.
+    pub(crate) fn start(verbosity: u8, path: Option<&Path>) -> Self {
+        let file = path.filter(|_| verbosity > 0).and_then(|path| {
+            OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(path)
+                .inspect_err(|e| {
+                    eprintln!("failed to open the trace file \"{}\": {e}", path.display());
+                })
+                .ok()
+        });
+
+        *TRACE_FILE.lock().unwrap_or_else(PoisonError::into_inner) = file;
+        VERBOSITY.store(verbosity, Ordering::Relaxed);
+        Self
+    }
+}
+
+impl Drop for TraceScope {
+    fn drop(&mut self) {
+        VERBOSITY.store(0, Ordering::Relaxed);
+        *TRACE_FILE.lock().unwrap_or_else(PoisonError::into_inner) = None;
+    }
+}
+
+/// Runs `func` without printing traces, e.g. for the samples of a benchmark.
+pub(crate) fn without_traces<T>(func: impl FnOnce() -> T) -> T {
+    let verbosity = VERBOSITY.swap(0, Ordering::Relaxed);
+    let result = func();
+    VERBOSITY.store(verbosity, Ordering::Relaxed);
+    result
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::ffi::OsString;
+
+    use super::verbosity_from_args;
+
+    fn verbosity(args: &[&str]) -> u8 {
+        let mut args = pico_args::Arguments::from_vec(args.iter().map(OsString::from).collect());
+        verbosity_from_args(&mut args)
+    }
+
+    #[test]
+    fn counts_verbosity_flags() {
+        assert_eq!(verbosity(&[]), 0);
+        assert_eq!(verbosity(&["-v"]), 1);
+        assert_eq!(verbosity(&["--verbose", "--part", "1", "-v"]), 2);
+        assert_eq!(verbosity(&["-vv"]), 2);
+    }
+}
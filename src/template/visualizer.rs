@@ -0,0 +1,626 @@
+/// Frames that solutions push to show their progress, recorded when a solution runs with `--visualize`.
+/// The frames of a step are played back in the terminal, or exported as images to `data/visualizations`.
+use std::error::Error;
+use std::ffi::OsString;
+use std::fmt::{Display, Write as _};
+use std::io::{IsTerminal, Write, stdout};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Mutex, PoisonError};
+use std::time::Duration;
+use std::{fs, io, thread};
+
+use crate::template::report::ReportStep;
+use crate::template::{ANSI_RESET, PuzzleId};
+
+static RECORDING: AtomicBool = AtomicBool::new(false);
+static FRAMES: Mutex<Vec<Frame>> = Mutex::new(vec![]);
+
+/// Playback shows at most this many frames, skipping frames evenly for longer recordings.
+const MAX_PLAYBACK_FRAMES: usize = 300;
+const FRAME_TIME: Duration = Duration::from_millis(40);
+/// How long the last frame stays on screen after a playback.
+const HOLD_TIME: Duration = Duration::from_secs(1);
+
+/// Point sets are scaled down to fit into these dimensions, in cells.
+const TERMINAL_SIZE: (usize, usize) = (120, 45);
+const IMAGE_SIZE: (usize, usize) = (500, 500);
+/// Width and height of a grid cell in PPM and SVG images, in pixels.
+const CELL_PIXELS: usize = 4;
+
+/// Pushes frames of a running part to the visualization.
+///
+/// ```ignore
+/// Visualizer::push(|| CharGrid::from_text(input));
+/// ```
+pub struct Visualizer;
+
+impl Visualizer {
+    /// Returns `true` if the solution runs with `--visualize`, e.g. to skip work that only serves the frames.
+    pub fn is_enabled() -> bool {
+        RECORDING.load(Ordering::Relaxed)
+    }
+
+    /// Records a frame. `frame` is only called when visualizing, not in tests or while benching.
+    pub fn push<F: Into<Frame>>(frame: impl FnOnce() -> F) {
+        if Self::is_enabled() {
+            let frame = frame().into();
+            FRAMES
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .push(frame);
+        }
+    }
+}
+
+/// A single frame of a visualization.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Frame {
+    Grid(CharGrid),
+    Points(PointSet),
+}
+
+impl From<CharGrid> for Frame {
+    fn from(grid: CharGrid) -> Self {
+        Self::Grid(grid)
+    }
+}
+
+impl From<PointSet> for Frame {
+    fn from(points: PointSet) -> Self {
+        Self::Points(points)
+    }
+}
+
+/// Colors of grid cells and points.
+/// `Default` is the terminal's text color, and a light gray in images.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Color {
+    #[default]
+    Default,
+    Gray,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+}
+
+impl Color {
+    fn ansi(self) -> &'static str {
+        match self {
+            Self::Default => "",
+            Self::Gray => "\x1b[90m",
+            Self::Red => "\x1b[31m",
+            Self::Green => "\x1b[32m",
+            Self::Yellow => "\x1b[33m",
+            Self::Blue => "\x1b[34m",
+            Self::Magenta => "\x1b[35m",
+            Self::Cyan => "\x1b[36m",
+            Self::White => "\x1b[97m",
+        }
+    }
+
+    fn rgb(self) -> [u8; 3] {
+        match self {
+            Self::Default => [0xcc, 0xcc, 0xcc],
+            Self::Gray => [0x66, 0x66, 0x66],
+            Self::Red => [0xff, 0x44, 0x44],
+            Self::Green => [0x00, 0xcc, 0x00],
+            Self::Yellow => [0xff, 0xff, 0x66],
+            Self::Blue => [0x44, 0x88, 0xff],
+            Self::Magenta => [0xcc, 0x44, 0xcc],
+            Self::Cyan => [0x44, 0xcc, 0xcc],
+            Self::White => [0xff, 0xff, 0xff],
+        }
+    }
+}
+
+/// The dark blue background of Advent of Code, for empty cells in images.
+const BACKGROUND_RGB: [u8; 3] = [0x0f, 0x0f, 0x23];
+
+/// A grid of colored characters. Cells outside of the grid are ignored.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CharGrid {
+    width: usize,
+    height: usize,
+    cells: Vec<(char, Color)>,
+}
+
+impl CharGrid {
+    /// Creates a grid filled with `.`.
+    pub fn new(width: usize, height: usize) -> Self {
+        Self::filled(width, height, '.')
+    }
+
+    fn filled(width: usize, height: usize, c: char) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![(c, Color::Default); width * height],
+        }
+    }
+
+    /// Creates a grid from the lines of a text, e.g. a puzzle input. Short lines are padded with spaces.
+    pub fn from_text(text: &str) -> Self {
+        let lines: Vec<&str> = text.lines().collect();
+        let width = lines
+            .iter()
+            .map(|line| line.chars().count())
+            .max()
+            .unwrap_or(0);
+
+        let mut grid = Self::filled(width, lines.len(), ' ');
+        for (y, line) in lines.iter().enumerate() {
+            for (x, c) in line.chars().enumerate() {
+                grid.set(x, y, c, Color::Default);
+            }
+        }
+        grid
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<(char, Color)> {
+        (x < self.width && y < self.height).then(|| self.cells[y * self.width + x])
+    }
+
+    pub fn set(&mut self, x: usize, y: usize, c: char, color: Color) {
+        if x < self.width && y < self.height {
+            self.cells[y * self.width + x] = (c, color);
+        }
+    }
+
+    fn rows(&self) -> impl Iterator<Item = &[(char, Color)]> {
+        self.cells.chunks(self.width.max(1)).take(self.height)
+    }
+}
+
+/// Empty cells are drawn as the background in images.
+fn is_empty_cell((c, color): (char, Color)) -> bool {
+    color == Color::Default && (c == '.' || c == ' ')
+}
+
+/// A set of colored points at arbitrary coordinates, e.g. the corners of a polygon.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PointSet {
+    points: Vec<((i64, i64), Color)>,
+}
+
+impl PointSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(&mut self, x: i64, y: i64, color: Color) {
+        self.points.push(((x, y), color));
+    }
+
+    /// Returns the top-left and bottom-right corners of the points.
+    fn bounds(&self) -> Option<((i64, i64), (i64, i64))> {
+        let xs = self.points.iter().map(|((x, _), _)| *x);
+        let ys = self.points.iter().map(|((_, y), _)| *y);
+        Some((
+            (xs.clone().min()?, ys.clone().min()?),
+            (xs.max()?, ys.max()?),
+        ))
+    }
+
+    /// Draws the points as `#` on a grid of at most `max_width` by `max_height` cells.
+    /// Larger sets are scaled down, so that several points can share a cell.
+    fn to_grid(&self, (max_width, max_height): (usize, usize)) -> CharGrid {
+        let Some(((min_x, min_y), (max_x, max_y))) = self.bounds() else {
+            return CharGrid::filled(0, 0, ' ');
+        };
+
+        let width = max_x.abs_diff(min_x) + 1;
+        let height = max_y.abs_diff(min_y) + 1;
+        let scale = f64::max(
+            1.0,
+            f64::max(
+                width as f64 / max_width as f64,
+                height as f64 / max_height as f64,
+            ),
+        );
+
+        let cell = |offset: u64| (offset as f64 / scale) as usize;
+        let mut grid = CharGrid::filled(cell(width - 1) + 1, cell(height - 1) + 1, ' ');
+        for ((x, y), color) in &self.points {
+            grid.set(
+                cell(x.abs_diff(min_x)),
+                cell(y.abs_diff(min_y)),
+                '#',
+                *color,
+            );
+        }
+        grid
+    }
+}
+
+impl FromIterator<(i64, i64)> for PointSet {
+    fn from_iter<T: IntoIterator<Item = (i64, i64)>>(iter: T) -> Self {
+        Self {
+            points: iter
+                .into_iter()
+                .map(|point| (point, Color::Default))
+                .collect(),
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// How `--visualize` shows the recorded frames.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum VisualizeMode {
+    /// An animated playback in the terminal.
+    #[default]
+    Terminal,
+    /// A sequence of PPM images.
+    Ppm,
+    /// A sequence of SVG images.
+    Svg,
+}
+
+impl Display for VisualizeMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Terminal => "terminal",
+            Self::Ppm => "ppm",
+            Self::Svg => "svg",
+        })
+    }
+}
+
+impl FromStr for VisualizeMode {
+    type Err = VisualizeModeFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "terminal" => Ok(Self::Terminal),
+            "ppm" => Ok(Self::Ppm),
+            "svg" => Ok(Self::Svg),
+            _ => Err(VisualizeModeFromStrError),
+        }
+    }
+}
+
+/// An error which can be returned when parsing a [`VisualizeMode`].
+#[derive(Debug)]
+pub struct VisualizeModeFromStrError;
+
+impl Error for VisualizeModeFromStrError {}
+
+impl Display for VisualizeModeFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting one of `terminal`, `ppm` or `svg`")
+    }
+}
+
+/// `--visualize` takes an optional mode, which `pico_args` does not support.
+/// Removes the flag and its mode (if it's a valid one) from the raw arguments.
+pub fn take_visualize(raw_args: &mut Vec<OsString>) -> Option<VisualizeMode> {
+    let index = raw_args.iter().position(|arg| arg == "--visualize")?;
+    raw_args.remove(index);
+
+    let mode = raw_args
+        .get(index)
+        .and_then(|arg| arg.to_str())
+        .and_then(|arg| arg.parse::<VisualizeMode>().ok());
+
+    if mode.is_some() {
+        raw_args.remove(index);
+    }
+
+    Some(mode.unwrap_or_default())
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Records the frames of a step while it is alive. [`VisualizeScope::finish`] shows them.
+pub(crate) struct VisualizeScope {
+    mode: Option<VisualizeMode>,
+    puzzle: PuzzleId,
+    step: ReportStep,
+}
+
+impl VisualizeScope {
+    pub(crate) fn start(mode: Option<VisualizeMode>, puzzle: PuzzleId, step: ReportStep) -> Self {
+        FRAMES
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clear();
+        RECORDING.store(mode.is_some(), Ordering::Relaxed);
+
+        Self { mode, puzzle, step }
+    }
+
+    /// Stops recording and shows the recorded frames, if any.
+    pub(crate) fn finish(self) {
+        RECORDING.store(false, Ordering::Relaxed);
+        let frames = std::mem::take(&mut *FRAMES.lock().unwrap_or_else(PoisonError::into_inner));
+
+        let Some(mode) = self.mode.filter(|_| !frames.is_empty()) else {
+            return;
+        };
+
+        match mode {
+            VisualizeMode::Terminal => play(&frames, self.step),
+            VisualizeMode::Ppm | VisualizeMode::Svg => {
+                let dir = visualization_dir(self.puzzle, self.step);
+                match export(&frames, mode, &dir) {
+                    Ok(()) => println!(
+                        "Wrote {} frame{} of {} to \"{}\".",
+                        frames.len(),
+                        if frames.len() == 1 { "" } else { "s" },
+                        self.step.as_str(),
+                        dir.display()
+                    ),
+                    Err(e) => eprintln!("failed to write the visualization: {e}"),
+                }
+            }
+        }
+    }
+}
+
+impl Drop for VisualizeScope {
+    fn drop(&mut self) {
+        RECORDING.store(false, Ordering::Relaxed);
+    }
+}
+
+/// Runs `func` without recording frames, e.g. for the samples of a benchmark.
+pub(crate) fn without_frames<T>(func: impl FnOnce() -> T) -> T {
+    let recording = RECORDING.swap(false, Ordering::Relaxed);
+    let result = func();
+    RECORDING.store(recording, Ordering::Relaxed);
+    result
+}
+
+/// E.g. `data/visualizations/2025/07/part_1`.
+fn visualization_dir(puzzle: PuzzleId, step: ReportStep) -> PathBuf {
+    let cwd = std::env::current_dir().unwrap();
+    cwd.join("data")
+        .join("visualizations")
+        .join(puzzle.year.to_string())
+        .join(puzzle.day.to_string())
+        .join(step.as_str())
+}
+
+/// Plays the frames on the alternate screen of the terminal, which is restored afterwards.
+fn play(frames: &[Frame], step: ReportStep) {
+    let mut stdout = stdout();
+    if !stdout.is_terminal() {
+        eprintln!(
+            "can only play a visualization in a terminal, try `--visualize ppm` or `--visualize svg`."
+        );
+        return;
+    }
+
+    let stride = frames.len().div_ceil(MAX_PLAYBACK_FRAMES);
+    let shown: Vec<usize> = (0..frames.len())
+        .step_by(stride)
+        .chain([frames.len() - 1])
+        .collect();
+
+    let _ = write!(stdout, "\x1b[?1049h\x1b[?25l");
+    for (i, index) in shown.iter().enumerate() {
+        if i > 0 && *index == shown[i - 1] {
+            continue;
+        }
+
+        let scaled;
+        let grid = match &frames[*index] {
+            Frame::Grid(grid) => grid,
+            Frame::Points(points) => {
+                scaled = points.to_grid(TERMINAL_SIZE);
+                &scaled
+            }
+        };
+        let _ = write!(
+            stdout,
+            "\x1b[H\x1b[2J{}\n{} · frame {}/{}",
+            render_text(grid),
+            step.as_str(),
+            index + 1,
+            frames.len()
+        );
+        let _ = stdout.flush();
+        thread::sleep(FRAME_TIME);
+    }
+    thread::sleep(HOLD_TIME);
+    let _ = write!(stdout, "\x1b[?25h\x1b[?1049l");
+    let _ = stdout.flush();
+}
+
+/// Renders a grid as text with ANSI colors.
+fn render_text(grid: &CharGrid) -> String {
+    let mut text = String::new();
+    for row in grid.rows() {
+        for (c, color) in row {
+            match color {
+                Color::Default => text.push(*c),
+                color => {
+                    let _ = write!(text, "{}{c}{ANSI_RESET}", color.ansi());
+                }
+            }
+        }
+        text.push('\n');
+    }
+    text
+}
+
+/// Writes the frames as numbered images, replacing an earlier visualization of the step.
+fn export(frames: &[Frame], mode: VisualizeMode, dir: &Path) -> io::Result<()> {
+    if dir.exists() {
+        fs::remove_dir_all(dir)?;
+    }
+    fs::create_dir_all(dir)?;
+
+    for (i, frame) in frames.iter().enumerate() {
+        let path = dir.join(format!("{:04}.{mode}", i + 1));
+        match mode {
+            VisualizeMode::Ppm => match frame {
+                Frame::Grid(grid) => fs::write(path, render_ppm(grid))?,
+                Frame::Points(points) => fs::write(path, render_ppm(&points.to_grid(IMAGE_SIZE)))?,
+            },
+            VisualizeMode::Svg => fs::write(path, render_svg(frame))?,
+            VisualizeMode::Terminal => unreachable!(),
+        }
+    }
+
+    Ok(())
+}
+
+/// Renders a grid as a binary PPM image, with a square of pixels per cell.
+fn render_ppm(grid: &CharGrid) -> Vec<u8> {
+    let (width, height) = (grid.width * CELL_PIXELS, grid.height * CELL_PIXELS);
+    let mut image = format!("P6\n{width} {height}\n255\n").into_bytes();
+
+    for row in grid.rows() {
+        for _ in 0..CELL_PIXELS {
+            for cell in row {
+                let rgb = if is_empty_cell(*cell) {
+                    BACKGROUND_RGB
+                } else {
+                    cell.1.rgb()
+                };
+                for _ in 0..CELL_PIXELS {
+                    image.extend(rgb);
+                }
+            }
+        }
+    }
+
+    image
+}
+
+/// Renders a frame as an SVG image. Points keep their coordinates, so the image scales without losing detail.
+fn render_svg(frame: &Frame) -> String {
+    let ((min_x, min_y), (width, height), cells): ((i64, i64), (u64, u64), Vec<_>) = match frame {
+        Frame::Grid(grid) => (
+            (0, 0),
+            (grid.width as u64, grid.height as u64),
+            grid.rows()
+                .enumerate()
+                .flat_map(|(y, row)| {
+                    row.iter()
+                        .enumerate()
+                        .filter(|(_, cell)| !is_empty_cell(**cell))
+                        .map(move |(x, (_, color))| ((x as i64, y as i64), *color))
+                })
+                .collect(),
+        ),
+        Frame::Points(points) => match points.bounds() {
+            Some(((min_x, min_y), (max_x, max_y))) => (
+                (min_x, min_y),
+                (max_x.abs_diff(min_x) + 1, max_y.abs_diff(min_y) + 1),
+                points.points.clone(),
+            ),
+            None => ((0, 0), (0, 0), vec![]),
+        },
+    };
+
+    let scale =
+        (IMAGE_SIZE.0 as f64 * CELL_PIXELS as f64 / width.max(height).max(1) as f64).max(1.0);
+    let [r, g, b] = BACKGROUND_RGB;
+
+    let mut svg = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"{min_x} {min_y} {width} {height}\" width=\"{:.0}\" height=\"{:.0}\" shape-rendering=\"crispEdges\">\n",
+        width as f64 * scale,
+        height as f64 * scale
+    );
+    let _ = writeln!(
+        svg,
+        "<rect x=\"{min_x}\" y=\"{min_y}\" width=\"{width}\" height=\"{height}\" fill=\"#{r:02x}{g:02x}{b:02x}\"/>"
+    );
+    for ((x, y), color) in cells {
+        let [r, g, b] = color.rgb();
+        let _ = writeln!(
+            svg,
+            "<rect x=\"{x}\" y=\"{y}\" width=\"1\" height=\"1\" fill=\"#{r:02x}{g:02x}{b:02x}\"/>"
+        );
+    }
+    svg.push_str("</svg>\n");
+    svg
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::ffi::OsString;
+
+    use super::{
+        CharGrid, Color, Frame, PointSet, VisualizeMode, render_ppm, render_svg, take_visualize,
+    };
+
+    #[test]
+    fn builds_grids() {
+        let mut grid = CharGrid::from_text("..S..\n.^\n");
+        assert_eq!((grid.width(), grid.height()), (5, 2));
+        assert_eq!(grid.get(2, 0), Some(('S', Color::Default)));
+        assert_eq!(grid.get(4, 1), Some((' ', Color::Default)));
+
+        grid.set(1, 1, '^', Color::Red);
+        grid.set(9, 9, '#', Color::Red);
+        assert_eq!(grid.get(1, 1), Some(('^', Color::Red)));
+        assert_eq!(grid.get(9, 9), None);
+    }
+
+    #[test]
+    fn scales_points_to_grids() {
+        let points: PointSet = [(0, 0), (1, 1)].into_iter().collect();
+        let grid = points.to_grid((10, 10));
+        assert_eq!((grid.width(), grid.height()), (2, 2));
+        assert_eq!(grid.get(1, 1), Some(('#', Color::Default)));
+        assert_eq!(grid.get(0, 1), Some((' ', Color::Default)));
+
+        let points: PointSet = [(-1000, 0), (999, 99)].into_iter().collect();
+        let grid = points.to_grid((100, 100));
+        assert_eq!((grid.width(), grid.height()), (100, 5));
+        assert_eq!(grid.get(99, 4), Some(('#', Color::Default)));
+    }
+
+    #[test]
+    fn renders_images() {
+        let mut grid = CharGrid::new(2, 1);
+        grid.set(1, 0, '#', Color::Red);
+
+        let ppm = render_ppm(&grid);
+        let header = b"P6\n8 4\n255\n";
+        assert_eq!(&ppm[..header.len()], header);
+        assert_eq!(ppm.len(), header.len() + 8 * 4 * 3);
+        assert_eq!(&ppm[header.len() + 4 * 3..][..3], &[0xff, 0x44, 0x44]);
+
+        let svg = render_svg(&Frame::Grid(grid));
+        assert!(svg.contains("viewBox=\"0 0 2 1\""));
+        assert!(svg.contains("<rect x=\"1\" y=\"0\" width=\"1\" height=\"1\" fill=\"#ff4444\"/>"));
+        assert!(!svg.contains("<rect x=\"0\" y=\"0\" width=\"1\""));
+    }
+
+    #[test]
+    fn takes_visualize_modes() {
+        let mut args: Vec<OsString> = ["--visualize", "svg", "--part", "1"]
+            .iter()
+            .map(OsString::from)
+            .collect();
+        assert_eq!(take_visualize(&mut args), Some(VisualizeMode::Svg));
+        assert_eq!(args, [OsString::from("--part"), OsString::from("1")]);
+
+        let mut args: Vec<OsString> = ["--visualize", "7"].iter().map(OsString::from).collect();
+        assert_eq!(take_visualize(&mut args), Some(VisualizeMode::Terminal));
+        assert_eq!(args, [OsString::from("7")]);
+
+        assert_eq!(take_visualize(&mut vec![]), None);
+    }
+}
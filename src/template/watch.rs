@@ -0,0 +1,185 @@
+/// Re-runs a command whenever the files of a day change.
+/// Changes are detected by polling modification times, which works on any file system, e.g. in containers.
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, SystemTime};
+use std::{env, fs};
+
+use crate::template::run_multi::get_path_for_bin;
+use crate::template::{ANSI_ITALIC, ANSI_RESET, PuzzleId, data_file_path};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Editors may write a file in several steps, changes are picked up once they have settled.
+const SETTLE_TIME: Duration = Duration::from_millis(100);
+
+/// Runs `run`, then runs it again after every change to the solution, examples or inputs of a day.
+/// Never returns, watching is stopped with Ctrl+C.
+pub(crate) fn watch(puzzle: PuzzleId, mut run: impl FnMut()) -> ! {
+    let mut times = modification_times(&watched_files(puzzle));
+    run();
+
+    loop {
+        println!(
+            "\n{ANSI_ITALIC}Watching {} for changes, press Ctrl+C to stop.{ANSI_RESET}",
+            describe_watched_files(puzzle)
+        );
+
+        let changed = loop {
+            thread::sleep(POLL_INTERVAL);
+            let current = modification_times(&watched_files(puzzle));
+            let changed = changed_files(&times, &current);
+            if !changed.is_empty() {
+                break changed;
+            }
+        };
+
+        thread::sleep(SETTLE_TIME);
+        // files changed while running are picked up by the next poll.
+        times = modification_times(&watched_files(puzzle));
+
+        let cwd = env::current_dir().unwrap_or_default();
+        let names: Vec<String> = changed
+            .iter()
+            .map(|path| {
+                path.strip_prefix(&cwd)
+                    .unwrap_or(path)
+                    .display()
+                    .to_string()
+            })
+            .collect();
+        println!("{ANSI_ITALIC}Changed: {}{ANSI_RESET}\n", names.join(", "));
+        run();
+    }
+}
+
+/// The solution of a day and all of its example and input files, e.g. `data/examples/2025/09-2.txt`.
+fn watched_files(puzzle: PuzzleId) -> Vec<PathBuf> {
+    let mut files = vec![PathBuf::from(get_path_for_bin(puzzle))];
+
+    for folder in ["examples", "inputs"] {
+        let path = data_file_path(folder, puzzle, None);
+        let Some(dir) = path.parent() else {
+            continue;
+        };
+        files.extend(day_files(dir, &puzzle.day.to_string()));
+    }
+
+    files
+}
+
+/// The text files of a folder whose name starts with `prefix`, e.g. `09.txt` and `09-1.txt`.
+fn day_files(dir: &Path, prefix: &str) -> Vec<PathBuf> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return vec![];
+    };
+
+    let mut files: Vec<PathBuf> = entries
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.extension().is_some_and(|ext| ext == "txt")
+                && path
+                    .file_name()
+                    .and_then(|name| name.to_str())
+                    .is_some_and(|name| name.starts_with(prefix))
+        })
+        .collect();
+
+    files.sort();
+    files
+}
+
+fn describe_watched_files(puzzle: PuzzleId) -> String {
+    let data_glob = |folder: &str| format!("data/{folder}/{}/{}*.txt", puzzle.year, puzzle.day);
+
+    format!(
+        "{}, {} and {}",
+        get_path_for_bin(puzzle),
+        data_glob("examples"),
+        data_glob("inputs")
+    )
+}
+
+/// The modification time of each file, files that can't be read are left out.
+fn modification_times(files: &[PathBuf]) -> HashMap<PathBuf, SystemTime> {
+    files
+        .iter()
+        .filter_map(|path| {
+            let modified = fs::metadata(path).and_then(|m| m.modified()).ok()?;
+            Some((path.clone(), modified))
+        })
+        .collect()
+}
+
+/// Files that were modified, added or removed between two polls.
+fn changed_files(
+    before: &HashMap<PathBuf, SystemTime>,
+    after: &HashMap<PathBuf, SystemTime>,
+) -> Vec<PathBuf> {
+    let mut changed: Vec<PathBuf> = after
+        .iter()
+        .filter(|(path, time)| before.get(*path) != Some(time))
+        .map(|(path, _)| path.clone())
+        .chain(
+            before
+                .keys()
+                .filter(|path| !after.contains_key(*path))
+                .cloned(),
+        )
+        .collect();
+
+    changed.sort();
+    changed
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::fs::{self, File};
+    use std::time::{Duration, SystemTime};
+    use std::{env, process};
+
+    use super::{changed_files, day_files, modification_times};
+
+    #[test]
+    fn detects_changed_files() {
+        let dir = env::temp_dir().join(format!("aoc-watch-test-{}", process::id()));
+        fs::create_dir_all(&dir).unwrap();
+
+        for name in ["09.txt", "09-1.txt", "10.txt", "09.md"] {
+            fs::write(dir.join(name), "").unwrap();
+        }
+
+        let files = day_files(&dir, "09");
+        assert_eq!(files, vec![dir.join("09-1.txt"), dir.join("09.txt")]);
+
+        let before = modification_times(&files);
+        assert!(changed_files(&before, &modification_times(&files)).is_empty());
+
+        let later = SystemTime::now() + Duration::from_secs(5);
+        File::options()
+            .write(true)
+            .open(dir.join("09.txt"))
+            .unwrap()
+            .set_modified(later)
+            .unwrap();
+        fs::write(dir.join("09-2.txt"), "").unwrap();
+        fs::remove_file(dir.join("09-1.txt")).unwrap();
+
+        let after = modification_times(&day_files(&dir, "09"));
+        let changed = changed_files(&before, &after);
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(
+            changed,
+            vec![
+                dir.join("09-1.txt"),
+                dir.join("09-2.txt"),
+                dir.join("09.txt")
+            ]
+        );
+    }
+}